@@ -0,0 +1,84 @@
+//! Compares the converters on a batch of names, `cargo bench` reports the
+//! time of a batch for each.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use wcase::{convert, convert_into, convert_lenient, get_case, split_words, words, Case};
+
+const NAMES: &[&str] = &[
+    "helloWorld",
+    "HelloWorld",
+    "hello_world",
+    "HELLO_WORLD",
+    "hello-world",
+    "Hello-World",
+    "hello world",
+    "Hello World",
+    "parse_http_response_header",
+    "maxConnectionPoolSize",
+    "DatabaseConnectionString",
+    "x",
+];
+
+fn converters(c: &mut Criterion) {
+    for to in [Case::Snake, Case::Camel, Case::HttpHeader] {
+        let mut group = c.benchmark_group(format!("to {}", to));
+        group.throughput(Throughput::Elements(NAMES.len() as u64));
+
+        group.bench_function("convert", |b| {
+            b.iter(|| {
+                for word in black_box(NAMES) {
+                    black_box(convert(word, get_case(word), to));
+                }
+            })
+        });
+
+        group.bench_function("convert_lenient", |b| {
+            b.iter(|| {
+                for word in black_box(NAMES) {
+                    black_box(convert_lenient(word, to));
+                }
+            })
+        });
+
+        group.bench_function("convert_into, reused buffer", |b| {
+            let mut buffer = String::new();
+
+            b.iter(|| {
+                for word in black_box(NAMES) {
+                    buffer.clear();
+                    convert_into(word, to, &mut buffer).unwrap();
+                    black_box(&buffer);
+                }
+            })
+        });
+
+        group.finish();
+    }
+}
+
+fn splitting(c: &mut Criterion) {
+    let mut group = c.benchmark_group("splitting");
+    group.throughput(Throughput::Elements(NAMES.len() as u64));
+
+    group.bench_function("split_words", |b| {
+        b.iter(|| {
+            for word in black_box(NAMES) {
+                black_box(split_words(word));
+            }
+        })
+    });
+
+    group.bench_function("words", |b| {
+        b.iter(|| {
+            for word in black_box(NAMES) {
+                black_box(words(word).count());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, converters, splitting);
+criterion_main!(benches);
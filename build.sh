@@ -31,3 +31,9 @@ for fileName in "${fileNames[@]}"; do
   fi
 done
 
+
+./bin/wcase --no-config man --dir ./bin
+
+for shell in bash elvish fish powershell zsh; do
+  ./bin/wcase --no-config completions ${shell} > ./bin/wcase.${shell}
+done
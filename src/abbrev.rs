@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("address", "addr"), ("administrator", "admin"), ("application", "app"),
+    ("argument", "arg"), ("attribute", "attr"), ("authentication", "auth"),
+    ("authorization", "authz"), ("average", "avg"), ("buffer", "buf"), ("calculate", "calc"),
+    ("character", "char"), ("column", "col"), ("command", "cmd"), ("configuration", "cfg"),
+    ("connection", "conn"), ("context", "ctx"), ("count", "cnt"), ("current", "cur"),
+    ("database", "db"), ("default", "dflt"), ("definition", "def"), ("description", "desc"),
+    ("destination", "dest"), ("directory", "dir"), ("document", "doc"),
+    ("environment", "env"), ("error", "err"), ("execute", "exec"), ("expression", "expr"),
+    ("function", "func"), ("identifier", "id"), ("image", "img"), ("increment", "incr"),
+    ("index", "idx"), ("information", "info"), ("initialize", "init"), ("length", "len"),
+    ("library", "lib"), ("management", "mgmt"), ("manager", "mgr"), ("maximum", "max"),
+    ("memory", "mem"), ("message", "msg"), ("minimum", "min"), ("number", "num"),
+    ("object", "obj"), ("parameter", "param"), ("position", "pos"), ("previous", "prev"),
+    ("quantity", "qty"), ("reference", "ref"), ("request", "req"), ("response", "resp"),
+    ("sequence", "seq"), ("source", "src"), ("specification", "spec"),
+    ("statistics", "stats"), ("string", "str"), ("synchronize", "sync"), ("system", "sys"),
+    ("table", "tbl"), ("temporary", "tmp"), ("transaction", "txn"), ("utility", "util"),
+    ("value", "val"), ("variable", "var"), ("version", "ver"),
+];
+
+/// Parses abbreviations written as `word=abbr`, one per line, skipping blank
+/// lines and `#` comments.
+pub fn parse_dictionary(text: &str) -> Result<Vec<(String, String)>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_entry)
+        .collect()
+}
+
+pub fn parse_entry(entry: &str) -> Result<(String, String), String> {
+    match entry.split_once('=') {
+        Some((word, abbr)) if !word.trim().is_empty() && !abbr.trim().is_empty() => {
+            Ok((word.trim().to_lowercase(), abbr.trim().to_lowercase()))
+        }
+        _ => Err(format!("Invalid abbreviation \"{}\", use WORD=ABBR", entry)),
+    }
+}
+
+/// Proposes a shorter form of a word.
+type Step<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Shortens identifiers to a maximum length, keeping them unique across a
+/// batch. Words are abbreviated from the dictionary first, then lose their
+/// vowels, are cut and dropped last, always taking the longest word first so
+/// the result is deterministic.
+pub struct Shortener {
+    max_len: usize,
+    dictionary: HashMap<String, String>,
+    seen: HashSet<String>,
+}
+
+impl Shortener {
+    /// Custom abbreviations take precedence over the built in ones.
+    pub fn new(max_len: usize, custom: Vec<(String, String)>) -> Shortener {
+        let mut dictionary: HashMap<String, String> = ABBREVIATIONS
+            .iter()
+            .map(|(word, abbr)| (word.to_string(), abbr.to_string()))
+            .collect();
+        dictionary.extend(custom);
+
+        Shortener {
+            max_len,
+            dictionary,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Joins the words, shortening them until the result fits. A result that
+    /// was already given out gets a number appended as an extra word, and the
+    /// other words are shortened further to make room for it.
+    pub fn shorten(&mut self, words: &[String], join: impl Fn(&[String]) -> String) -> String {
+        let mut result = self.fit(words.to_vec(), &join);
+        let mut n = 2;
+
+        while self.seen.contains(&result) {
+            let mut numbered = words.to_vec();
+            numbered.push(n.to_string());
+            result = self.fit(numbered, &join);
+            n += 1;
+        }
+
+        self.seen.insert(result.clone());
+        result
+    }
+
+    fn fit(&self, mut words: Vec<String>, join: &impl Fn(&[String]) -> String) -> String {
+        let fits = |words: &[String]| join(words).chars().count() <= self.max_len;
+
+        let steps: [Step; 3] = [
+            &|word| self.dictionary.get(&word.to_lowercase()).cloned(),
+            &|word| drop_vowels(word),
+            &|word| {
+                let len = word.chars().count();
+                (len > 1).then(|| word.chars().take(len - 1).collect())
+            },
+        ];
+
+        for step in steps {
+            while !fits(&words) {
+                let mut order: Vec<usize> = (0..words.len()).collect();
+                order.sort_by_key(|&i| std::cmp::Reverse(words[i].chars().count()));
+
+                let shortened = order.into_iter().find_map(|i| {
+                    if words[i].chars().all(|c| c.is_ascii_digit()) {
+                        return None;
+                    }
+
+                    step(&words[i])
+                        .filter(|short| short.chars().count() < words[i].chars().count())
+                        .map(|short| (i, short))
+                });
+
+                match shortened {
+                    Some((i, short)) => words[i] = short,
+                    None => break,
+                }
+            }
+        }
+
+        // Every word is down to a letter, drop the last ones but a number.
+        while !fits(&words) {
+            match words
+                .iter()
+                .rposition(|word| !word.chars().all(|c| c.is_ascii_digit()))
+            {
+                Some(i) if words.len() > 1 => words.remove(i),
+                _ => break,
+            };
+        }
+
+        join(&words)
+    }
+}
+
+/// Drops every vowel but the first letter, as in `nmbr`.
+fn drop_vowels(word: &str) -> Option<String> {
+    let mut chars = word.chars();
+    let first = chars.next()?;
+
+    let rest: String = chars
+        .filter(|c| !matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u'))
+        .collect();
+
+    Some(format!("{}{}", first, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{join_words, split_words, Case};
+
+    fn shorten(shortener: &mut Shortener, text: &str, to: Case) -> String {
+        shortener.shorten(&split_words(text), |words| join_words(words, to))
+    }
+
+    #[test]
+    fn test_shorten() {
+        let mut shortener = Shortener::new(30, vec![]);
+
+        assert_eq!(
+            shorten(&mut shortener, "short name", Case::AllCaps),
+            "SHORT_NAME"
+        );
+        assert_eq!(
+            shorten(
+                &mut shortener,
+                "maximum_configuration_retry_number_of_attempts",
+                Case::AllCaps
+            ),
+            "MAX_CFG_RETRY_NUM_OF_ATTEMPTS"
+        );
+        assert_eq!(
+            shorten(
+                &mut shortener,
+                "customer_shipping_destination_address_override",
+                Case::Snake
+            ),
+            "cstmr_shppng_dest_addr_ovrrd"
+        );
+
+        let mut shortener = Shortener::new(8, vec![]);
+
+        assert_eq!(
+            shorten(&mut shortener, "extraordinarily long", Case::Camel),
+            "extrrLng"
+        );
+    }
+
+    #[test]
+    fn test_custom_and_unique() {
+        let custom = parse_dictionary("# custom\ncustomer = cust\n\nnumber=no\n").unwrap();
+        let mut shortener = Shortener::new(10, custom);
+
+        assert_eq!(
+            shorten(&mut shortener, "customer number", Case::Snake),
+            "cust_no"
+        );
+        assert_eq!(
+            shorten(&mut shortener, "customer-number", Case::Snake),
+            "cust_no_2"
+        );
+        assert_eq!(
+            shorten(&mut shortener, "customerNumber", Case::Camel),
+            "custNumber"
+        );
+
+        assert!(parse_entry("customer").is_err());
+    }
+
+    #[test]
+    fn test_numbers_fit() {
+        let mut shortener = Shortener::new(8, vec![]);
+
+        assert_eq!(
+            shorten(&mut shortener, "customer_account_number", Case::Snake),
+            "cs_ac_nm"
+        );
+        assert_eq!(
+            shorten(&mut shortener, "customer_account_name", Case::Snake),
+            "c_a_nm_2"
+        );
+
+        let mut shortener = Shortener::new(5, vec![]);
+
+        assert_eq!(shorten(&mut shortener, "a b c", Case::Snake), "a_b_c");
+        assert_eq!(shorten(&mut shortener, "a b c", Case::Snake), "a_b_2");
+        assert_eq!(shorten(&mut shortener, "a b c", Case::Camel), "aBC");
+        assert_eq!(shorten(&mut shortener, "a-b-c-d", Case::Snake), "a_b_3");
+    }
+}
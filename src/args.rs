@@ -1,9 +1,274 @@
-use clap::Parser;
+use crate::check::{Mode, Style};
+use crate::config::Config;
+use crate::custom::{CustomCase, Target};
+use crate::lang::{Escape, Language};
+use crate::preset::Preset;
+use crate::sanitize::LeadingDigit;
+use crate::Case;
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use std::ffi::OsStr;
+use std::path::PathBuf;
 
+/// Names of the built in cases, offered by the help and the completions.
+pub const CASE_NAMES: [&str; 11] = [
+    "flat",
+    "upper",
+    "camel",
+    "pascal",
+    "snake",
+    "all_caps",
+    "kebab",
+    "train",
+    "spaced",
+    "title",
+    "http_header",
+];
+
+/// Takes any case name, as cases can also be defined with --define, but
+/// lists the built in ones so that they are completed.
+#[derive(Debug, Clone)]
+pub struct CaseName;
+
+impl TypedValueParser for CaseName {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<String, clap::Error> {
+        clap::builder::StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(CASE_NAMES.iter().map(PossibleValue::new)))
+    }
+}
+
+// Without a subcommand the options of `convert` are taken, as in
+// `wcase --snake -w helloWorld`. The configured case is then only used
+// along with an option that converts, so `wcase -w helloWorld` detects.
 /// word case
 #[derive(Debug, Parser)]
-#[command(author, about, long_about = None)]
+#[command(author, about, long_about = None, subcommand_negates_reqs = true)]
 pub struct Args {
+    #[command(flatten)]
+    pub convert: ConvertArgs,
+
+    /// define a case from a template like "k_{lower}_{lower}", with
+    /// {lower}, {upper} or {capital} for the first word and the rest
+    #[clap(long, value_name = "NAME=TEMPLATE", global = true)]
+    pub define: Vec<CustomCase>,
+
+    /// ignore the configuration files
+    #[clap(long, global = true)]
+    pub no_config: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Default, clap::Args)]
+pub struct ConvertArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// transform to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with_all = ["CaseArgs", "preset"]
+    )]
+    pub to: Option<String>,
+
+    /// transform to the naming convention of a language or format
+    #[clap(
+        long = "for",
+        value_enum,
+        value_name = "PRESET",
+        conflicts_with = "CaseArgs"
+    )]
+    pub preset: Option<Preset>,
+
+    /// language whose keywords are escaped in the result, taken from the
+    /// preset when missing
+    #[clap(long, short, value_enum)]
+    pub lang: Option<Language>,
+
+    /// how keywords are escaped: suffix, raw, quote or a template like
+    /// "k_{}", defaults to the usual style of the language
+    #[clap(long, short = 'E', value_name = "STYLE")]
+    pub escape: Option<Escape>,
+
+    /// transliterate accented, cyrillic and greek letters to ascii first
+    #[clap(long)]
+    pub ascii: bool,
+
+    /// split flat and upper case words like "usernamefield" into known words
+    #[clap(long)]
+    pub segment: bool,
+
+    /// file of extra words for --segment, one per line, ranked before the
+    /// built in ones
+    #[clap(long, value_name = "FILE", requires = "segment")]
+    pub dict: Option<PathBuf>,
+
+    /// turn any text into a valid identifier, dropping punctuation and
+    /// symbols before transforming it
+    #[clap(long)]
+    pub sanitize: bool,
+
+    /// what to do with leading digits when sanitizing [default: prefix]
+    #[clap(long, value_enum)]
+    pub leading_digit: Option<LeadingDigit>,
+
+    /// prefix of identifiers that start with a digit [default: _]
+    #[clap(long)]
+    pub digit_prefix: Option<String>,
+
+    /// initialism kept in upper case by the go presets, can be repeated
+    #[clap(long, value_name = "WORD")]
+    pub acronym: Vec<String>,
+
+    /// make a url slug, for each line of stdin when no word is given
+    #[clap(long, conflicts_with_all = ["CaseArgs", "preset", "sanitize"])]
+    pub slug: bool,
+
+    /// maximum length of the result. Slugs are cut on a word boundary, other
+    /// names are abbreviated
+    #[clap(long, value_name = "N")]
+    pub max_len: Option<usize>,
+
+    /// abbreviation used to shorten names, can be repeated
+    #[clap(long, value_name = "WORD=ABBR", requires = "max_len")]
+    pub abbrev: Vec<String>,
+
+    /// file of abbreviations, one WORD=ABBR per line
+    #[clap(long, value_name = "FILE", requires = "max_len")]
+    pub abbrev_file: Option<PathBuf>,
+
+    /// drop stop words like "the" or "of" from slugs
+    #[clap(long, requires = "slug")]
+    pub stop_words: bool,
+
+    /// number repeated slugs of a batch with 2, 3, ..., names shortened by
+    /// --max-len are always unique
+    #[clap(long, requires = "slug")]
+    pub unique: bool,
+
+    /// word to transform, each line of stdin is transformed when missing
+    /// with --slug or --max-len
+    #[clap(long, short, required_unless_present_any = ["slug", "max_len"])]
+    pub word: Option<String>,
+
+    /// the options were given without the convert subcommand
+    #[clap(skip)]
+    pub shorthand: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// transform a word, to the configured case when none is given, or print
+    /// its case without either
+    Convert(ConvertArgs),
+
+    /// print the case of words
+    Detect(DetectArgs),
+
+    /// rename files to a case, keeping their extensions
+    Rename(RenameArgs),
+
+    /// transform the keys of a json document
+    Json(JsonArgs),
+
+    /// transform the keys of a yaml document, keeping its layout
+    Yaml(KeysArgs),
+
+    /// transform the keys of a toml document, keeping its layout
+    Toml(KeysArgs),
+
+    /// transform the header row of a csv file
+    Csv(CsvArgs),
+
+    /// transform config keys into environment variable names and back
+    Env(EnvArgs),
+
+    /// check that words follow a case convention
+    Check(CheckArgs),
+
+    /// transform the identifiers of source files
+    Files(FilesArgs),
+
+    /// inspect the configuration files
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// print a completion script for a shell
+    Completions(CompletionsArgs),
+
+    /// print the man page
+    Man(ManArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// print the effective configuration and the file of each value
+    Show,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct CompletionsArgs {
+    /// shell to complete in
+    #[clap(value_enum)]
+    pub shell: Shell,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ManArgs {
+    /// write a page for wcase and each subcommand to this directory instead
+    #[clap(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct DetectArgs {
+    /// words to detect the case of, each line of stdin when missing
+    pub words: Vec<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct RenameArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// rename to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// print the new names without renaming anything
+    #[clap(long, short = 'n')]
+    pub dry_run: bool,
+
+    /// files and directories to rename
+    #[clap(required = true)]
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Default, clap::Args)]
+pub struct CaseArgs {
     /// transform to flat case
     #[clap(long)]
     pub flat: bool,
@@ -40,15 +305,429 @@ pub struct Args {
     #[clap(long)]
     pub spaced: bool,
 
-    // transform to http header case
+    /// transform to http header case
     #[clap(long)]
     pub http_header: bool,
 
     /// transform to title case
     #[clap(long)]
     pub title: bool,
+}
+
+impl CaseArgs {
+    pub fn target(&self) -> Option<Case> {
+        match self {
+            CaseArgs { flat: true, .. } => Some(Case::Flat),
+            CaseArgs { upper: true, .. } => Some(Case::Upper),
+            CaseArgs { camel: true, .. } => Some(Case::Camel),
+            CaseArgs { pascal: true, .. } => Some(Case::Pascal),
+            CaseArgs { snake: true, .. } => Some(Case::Snake),
+            CaseArgs { all_caps: true, .. } => Some(Case::AllCaps),
+            CaseArgs { kebab: true, .. } => Some(Case::Kebab),
+            CaseArgs { train: true, .. } => Some(Case::Train),
+            CaseArgs { spaced: true, .. } => Some(Case::Spaced),
+            CaseArgs { title: true, .. } => Some(Case::Title),
+            CaseArgs {
+                http_header: true, ..
+            } => Some(Case::HttpHeader),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct JsonArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// transform to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// only transform keys at or under this json pointer, `*` matches any key
+    #[clap(long, value_name = "PATH")]
+    pub include: Vec<String>,
+
+    /// leave keys at or under this json pointer untouched, `*` matches any key
+    #[clap(long, value_name = "PATH")]
+    pub exclude: Vec<String>,
+
+    /// also transform string values
+    #[clap(long)]
+    pub values: bool,
+
+    /// fail when two keys of the same object transform to the same key
+    #[clap(long)]
+    pub strict: bool,
+
+    /// json file to read, defaults to stdin
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct KeysArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
 
-    /// word to transform
+    /// transform to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// only check that every key is in the target case
+    #[clap(long)]
+    pub check: bool,
+
+    /// file to read, defaults to stdin
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct CsvArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// transform to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// transform the values of this column instead of the header row
+    #[clap(long, value_name = "HEADER")]
+    pub column: Option<String>,
+
+    /// transliterate accented, cyrillic and greek letters to ascii first
+    #[clap(long)]
+    pub ascii: bool,
+
+    /// field delimiter
+    #[clap(long, short, default_value_t = ',')]
+    pub delimiter: char,
+
+    /// use tabs as the field delimiter
+    #[clap(long, conflicts_with = "delimiter")]
+    pub tsv: bool,
+
+    /// fail when the conversion produces duplicate headers
+    #[clap(long)]
+    pub strict: bool,
+
+    /// csv file to read, defaults to stdin
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct EnvArgs {
+    /// case of the config keys when transforming environment variable names
+    /// back, defaults to camel case
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// case of the config keys by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// prefix of every environment variable name
+    #[clap(long, short)]
+    pub prefix: Option<String>,
+
+    /// separator between nesting levels in environment variable names
+    #[clap(long, short, default_value = "__")]
+    pub separator: String,
+
+    /// transform environment variable names into config keys
+    #[clap(long, short)]
+    pub reverse: bool,
+
+    /// rewrite the keys of this .env file
+    #[clap(long, short, conflicts_with = "keys")]
+    pub file: Option<PathBuf>,
+
+    /// keys to transform, a .env file is read from stdin when missing
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct CheckArgs {
+    /// case every word must be in, built in or defined with --define
+    #[clap(
+        long,
+        short,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true
+    )]
+    pub expect: String,
+
+    /// word to check, can be repeated
+    #[clap(long, short)]
+    pub word: Vec<String>,
+
+    /// which words of the files to check
+    #[clap(long, short, value_enum, default_value_t = Mode::Words)]
+    pub mode: Mode,
+
+    /// how offenders are reported
+    #[clap(long, short, value_enum, default_value_t = Style::Human)]
+    pub style: Style,
+
+    /// files to check, defaults to stdin when no word is given
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct FilesArgs {
+    #[command(flatten)]
+    pub case: CaseArgs,
+
+    /// transform to a case by name, built in or defined with --define
+    #[clap(
+        long,
+        value_name = "CASE",
+        value_parser = CaseName,
+        hide_possible_values = true,
+        conflicts_with = "CaseArgs"
+    )]
+    pub to: Option<String>,
+
+    /// only identifiers in this case are transformed
+    #[clap(
+        long,
+        short,
+        value_name = "CASE",
+        value_parser = CaseName.try_map(|name| name.parse::<Case>())
+    )]
+    pub from: Case,
+
+    /// language of the files, guessed from their extension when missing
+    #[clap(long, short, value_enum)]
+    pub lang: Option<Language>,
+
+    /// also transform words inside strings
+    #[clap(long)]
+    pub strings: bool,
+
+    /// also transform words inside comments
+    #[clap(long)]
+    pub comments: bool,
+
+    /// escape new names that are keywords instead of keeping the old name:
+    /// suffix, raw, quote or a template like "k_{}"
+    #[clap(long, short = 'E', value_name = "STYLE")]
+    pub escape: Option<Escape>,
+
+    /// rewrite the files instead of printing them
     #[clap(long, short)]
-    pub word: String,
+    pub in_place: bool,
+
+    /// files to transform, defaults to stdin
+    pub files: Vec<PathBuf>,
+}
+
+impl Args {
+    /// Parses the command line like `Args::parse`, but rejects the options of
+    /// `convert` given before a subcommand, which it would ignore.
+    pub fn parse_checked() -> Args {
+        let mut command = Args::command();
+        let matches = command.get_matches_mut();
+
+        if let Some((name, _)) = matches.subcommand() {
+            let ignored = command.get_arguments().find(|arg| {
+                !arg.is_global_set()
+                    && matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+            });
+
+            if let Some(arg) = ignored {
+                let message = format!(
+                    "{} is an option of convert, it can't come before the {} subcommand",
+                    arg, name
+                );
+                command.error(ErrorKind::ArgumentConflict, message).exit();
+            }
+        }
+
+        Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+    }
+
+    /// The subcommand to run, `convert` with the top level options when
+    /// none is given.
+    pub fn take_command(&mut self) -> Command {
+        match self.command.take() {
+            Some(command) => command,
+            None => Command::Convert(ConvertArgs {
+                shorthand: true,
+                ..std::mem::take(&mut self.convert)
+            }),
+        }
+    }
+
+    /// Adds the cases defined in the configuration after the ones given on
+    /// the command line.
+    pub fn merge(&mut self, config: &Config) -> Result<(), String> {
+        for (name, template) in config.table("cases") {
+            let case = CustomCase::new(&name, &template)
+                .map_err(|e| located(config, &format!("cases.{}", name), e))?;
+            self.define.push(case);
+        }
+
+        Ok(())
+    }
+}
+
+/// Prefixes an error about a configuration value with its file.
+fn located(config: &Config, key: &str, e: String) -> String {
+    match config.get(key) {
+        Some(entry) => format!("{}: {}", entry.source.display(), e),
+        None => e,
+    }
+}
+
+impl Command {
+    /// Fills in what the command line leaves unset from the configuration,
+    /// whose case may be one of `custom`.
+    pub fn merge(&mut self, config: &Config, custom: &[CustomCase]) -> Result<(), String> {
+        let (to, case) = match self {
+            Command::Convert(args) => return args.merge(config, custom),
+            Command::Rename(args) => (&mut args.to, &args.case),
+            Command::Json(args) => (&mut args.to, &args.case),
+            Command::Yaml(args) | Command::Toml(args) => (&mut args.to, &args.case),
+            Command::Csv(args) => (&mut args.to, &args.case),
+            Command::Env(args) => (&mut args.to, &args.case),
+            Command::Files(args) => (&mut args.to, &args.case),
+            _ => return Ok(()),
+        };
+
+        if to.is_some() || case.target().is_some() {
+            return Ok(());
+        }
+
+        if let Some(name) = config.string("case") {
+            Target::resolve(&name, custom).map_err(|e| located(config, "case", e))?;
+            *to = Some(name);
+        }
+
+        Ok(())
+    }
+}
+
+impl ConvertArgs {
+    /// Cases and abbreviations of the configuration come after the ones
+    /// given on the command line. The case and the options that need one
+    /// apply to the convert subcommand, but only along with an option that
+    /// converts without it, so that `wcase -w helloWorld` still detects.
+    fn merge(&mut self, config: &Config, custom: &[CustomCase]) -> Result<(), String> {
+        let has_target =
+            self.to.is_some() || self.case.target().is_some() || self.preset.is_some() || self.slug;
+        let mut converts = has_target || self.sanitize || self.segment || self.max_len.is_some();
+
+        if !has_target && (converts || !self.shorthand) {
+            if let Some(name) = config.string("case") {
+                Target::resolve(&name, custom).map_err(|e| located(config, "case", e))?;
+                self.to = Some(name);
+                converts = true;
+            }
+        }
+
+        if self.lang.is_none() {
+            if let Some(lang) = config.string("lang") {
+                let lang =
+                    Language::from_str(&lang, true).map_err(|e| located(config, "lang", e))?;
+                self.lang = Some(lang);
+            }
+        }
+
+        if self.escape.is_none() {
+            if let Some(escape) = config.string("escape") {
+                self.escape = Some(escape.parse().map_err(|e| located(config, "escape", e))?);
+            }
+        }
+
+        if self.leading_digit.is_none() {
+            if let Some(policy) = config.string("leading-digit") {
+                let policy = LeadingDigit::from_str(&policy, true)
+                    .map_err(|e| located(config, "leading-digit", e))?;
+                self.leading_digit = Some(policy);
+            }
+        }
+
+        if self.digit_prefix.is_none() {
+            self.digit_prefix = config.string("digit-prefix");
+        }
+
+        if self.dict.is_none() {
+            self.dict = config.path("dict");
+        }
+
+        if self.max_len.is_none() && !self.slug && converts {
+            self.max_len = config.integer("max-len");
+        }
+
+        self.ascii |= config.boolean("ascii");
+        self.sanitize |= config.boolean("sanitize") && converts;
+        self.segment |= config.boolean("segment") && converts;
+        self.acronym.extend(config.strings("acronyms"));
+
+        let mut abbrev: Vec<String> = config
+            .table("abbreviations")
+            .into_iter()
+            .map(|(word, abbr)| format!("{}={}", word, abbr))
+            .collect();
+        abbrev.append(&mut self.abbrev);
+        self.abbrev = abbrev;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_args() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_case_names() {
+        for name in CASE_NAMES {
+            assert_eq!(Case::from_str(name).unwrap().to_string(), name);
+        }
+
+        let args = Args::try_parse_from(["wcase", "--to", "custom", "-w", "x"]).unwrap();
+        assert_eq!(args.convert.to.as_deref(), Some("custom"));
+
+        let args = Args::try_parse_from(["wcase", "files", "--from", "ALL-CAPS"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Command::Files(FilesArgs {
+                from: Case::AllCaps,
+                ..
+            }))
+        ));
+    }
 }
@@ -0,0 +1,163 @@
+/// Romanizes accented latin, cyrillic and greek letters, leaving ascii as it
+/// is. Other letters are dropped, and other symbols become spaces so they
+/// still separate words.
+pub fn transliterate(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            result.push(c);
+            continue;
+        }
+
+        let lower = c.to_lowercase().next().unwrap_or(c);
+
+        let latin = match romanize(lower) {
+            Some(latin) => latin,
+            None if is_combining_mark(c) || c.is_alphanumeric() => continue,
+            None => {
+                result.push(' ');
+                continue;
+            }
+        };
+
+        let previous = i.checked_sub(1).and_then(|i| chars.get(i));
+        let next = chars.get(i + 1);
+        let in_upper_word = previous.or(next).is_some_and(|c| c.is_uppercase())
+            && !next.is_some_and(|c| c.is_lowercase());
+
+        if c.is_uppercase() && !in_upper_word {
+            result.push_str(&capitalize(latin));
+        } else if c.is_uppercase() || in_upper_word && lower == 'ß' {
+            result.push_str(&latin.to_uppercase());
+        } else {
+            result.push_str(latin);
+        }
+    }
+
+    result
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn is_combining_mark(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// Latin spelling of a lowercase letter. German umlauts are spelled out, as
+/// in `groesse`, other accents are simply dropped.
+fn romanize(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'à' | 'á' | 'â' | 'ã' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ä' | 'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĳ' => "ij",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ō' | 'ŏ' | 'ő' | 'ø' => "o",
+        'ö' | 'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ü' => "ue",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'э' => "e",
+        'ё' => "yo",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ї' => "yi",
+        'й' | 'ы' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' | 'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'θ' => "th",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' | 'ω' | 'ώ' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(transliterate("Café Crème"), "Cafe Creme");
+        assert_eq!(transliterate("Größe Maß"), "Groesse Mass");
+        assert_eq!(transliterate("GRÖẞE"), "GROESSE");
+        assert_eq!(transliterate("STRAßE"), "STRASSE");
+        assert_eq!(transliterate("Ökonomie"), "Oekonomie");
+        assert_eq!(transliterate("Москва Щука"), "Moskva Shchuka");
+        assert_eq!(transliterate("ЩИ"), "SHCHI");
+        assert_eq!(transliterate("Αθήνα"), "Athina");
+        assert_eq!(transliterate("cafe\u{301}"), "cafe");
+        assert_eq!(transliterate("a—b 東京"), "a b ");
+    }
+}
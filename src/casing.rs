@@ -0,0 +1,199 @@
+use crate::{convert, convert_lenient, get_case, is_title_case, Case};
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// Case detection and conversion as methods of strings:
+/// `"HelloWorld".to_snake()` or `name.to_case(Case::Kebab)`.
+///
+/// Words of no detectable case, like `E-mail Address`, are converted through
+/// `split_words`.
+pub trait Casing {
+    /// The case of the word, `Case::None` when it has none.
+    fn case(&self) -> Case;
+
+    /// Whether the word is already in `case`, which a word can be in several
+    /// of: `hello` is flat, camel, snake, kebab and spaced.
+    fn is_case(&self, case: Case) -> bool;
+
+    /// The word in `to`, borrowed when it's already in it.
+    fn to_case_cow(&self, to: Case) -> Cow<'_, str>;
+
+    fn to_case(&self, to: Case) -> String {
+        self.to_case_cow(to).into_owned()
+    }
+
+    fn to_flat(&self) -> String {
+        self.to_case(Case::Flat)
+    }
+
+    fn to_upper(&self) -> String {
+        self.to_case(Case::Upper)
+    }
+
+    fn to_camel(&self) -> String {
+        self.to_case(Case::Camel)
+    }
+
+    fn to_pascal(&self) -> String {
+        self.to_case(Case::Pascal)
+    }
+
+    fn to_snake(&self) -> String {
+        self.to_case(Case::Snake)
+    }
+
+    fn to_all_caps(&self) -> String {
+        self.to_case(Case::AllCaps)
+    }
+
+    fn to_kebab(&self) -> String {
+        self.to_case(Case::Kebab)
+    }
+
+    fn to_train(&self) -> String {
+        self.to_case(Case::Train)
+    }
+
+    fn to_spaced(&self) -> String {
+        self.to_case(Case::Spaced)
+    }
+
+    fn to_title(&self) -> String {
+        self.to_case(Case::Title)
+    }
+
+    fn to_http_header(&self) -> String {
+        self.to_case(Case::HttpHeader)
+    }
+}
+
+impl Casing for str {
+    fn case(&self) -> Case {
+        get_case(self)
+    }
+
+    fn is_case(&self, case: Case) -> bool {
+        if case == Case::None {
+            return false;
+        }
+
+        match get_case(self) {
+            Case::None => false,
+            Case::Flat => matches!(
+                case,
+                Case::Flat | Case::Camel | Case::Snake | Case::Kebab | Case::Spaced
+            ),
+            Case::Upper => matches!(case, Case::Upper | Case::AllCaps | Case::Train),
+            Case::Pascal if is_title_case(self) => {
+                matches!(case, Case::Pascal | Case::Title | Case::HttpHeader)
+            }
+            detected => detected == case,
+        }
+    }
+
+    fn to_case_cow(&self, to: Case) -> Cow<'_, str> {
+        if self.is_case(to) {
+            return Cow::Borrowed(self);
+        }
+
+        match get_case(self) {
+            Case::None => Cow::Owned(convert_lenient(self, to)),
+            case => Cow::Owned(convert(self, case, to)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CASES: [Case; 11] = [
+        Case::Flat,
+        Case::Upper,
+        Case::Camel,
+        Case::Pascal,
+        Case::Snake,
+        Case::AllCaps,
+        Case::Kebab,
+        Case::Train,
+        Case::Spaced,
+        Case::Title,
+        Case::HttpHeader,
+    ];
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!("HelloWorld".to_snake(), "hello_world");
+        assert_eq!("hello_world".to_camel(), "helloWorld");
+        assert_eq!(
+            String::from("helloWorld").to_case(Case::Kebab),
+            "hello-world"
+        );
+        assert_eq!("hello world".to_http_header(), "Hello-World");
+        assert_eq!("E-mail Address".to_snake(), "e_mail_address");
+        assert_eq!("HELLO-WORLD".case(), Case::Train);
+    }
+
+    #[test]
+    fn test_empty_and_separators() {
+        for case in CASES {
+            assert_eq!("".to_case(case), "", "to {}", case);
+        }
+
+        // separators are kept by the cases that have some, the others have
+        // nothing left
+        for word in ["_", "-", " ", "__"] {
+            assert_eq!(word.to_camel(), "");
+            assert_eq!(word.to_pascal(), "");
+            assert_eq!(word.to_title(), "");
+            assert_eq!(word.to_http_header(), "");
+            assert_eq!(word.to_flat(), "");
+            assert_eq!(word.to_snake(), "_".repeat(word.len()));
+            assert_eq!(word.to_kebab(), "-".repeat(word.len()));
+        }
+    }
+
+    #[test]
+    fn test_is_case() {
+        assert!("hello".is_case(Case::Camel));
+        assert!("hello".is_case(Case::Snake));
+        assert!(!"hello".is_case(Case::Pascal));
+        assert!("HELLO".is_case(Case::AllCaps));
+        assert!("Hello".is_case(Case::Title));
+        assert!(!"HelloWorld".is_case(Case::Title));
+        assert!(!"hello_World".is_case(Case::Snake));
+        assert!(!"hello".is_case(Case::None));
+    }
+
+    #[test]
+    fn test_cow_borrows_words_in_the_case() {
+        let words = [
+            "hello",
+            "HELLO",
+            "Hello",
+            "helloWorld",
+            "HelloWorld",
+            "hello_world",
+            "HELLO_WORLD",
+            "hello-world",
+            "HELLO-WORLD",
+            "hello world",
+            "Hello World",
+            "Hello-World",
+        ];
+
+        for word in words {
+            for case in CASES {
+                let converted = word.to_case_cow(case);
+
+                match converted {
+                    Cow::Borrowed(borrowed) => assert_eq!(borrowed, word),
+                    Cow::Owned(owned) => {
+                        assert_ne!(owned, word, "{} to {}", word, case);
+                        assert!(owned.is_case(case), "{} to {}", word, case);
+                    }
+                }
+            }
+        }
+    }
+}
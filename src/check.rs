@@ -0,0 +1,267 @@
+use crate::css;
+use crate::custom::Target;
+use crate::keys::{self, Format};
+use crate::scanner::position;
+use crate::{convert_affixed, convert_lenient, get_case, split_words, Case};
+use clap::ValueEnum;
+
+/// How words are picked out of the input.
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Mode {
+    /// every whitespace separated word
+    Words,
+    /// every non empty line
+    Lines,
+    /// every object key of a json document
+    Json,
+    /// every mapping key of a yaml document
+    Yaml,
+    /// every key of a toml document
+    Toml,
+    /// every class name of the selectors of a css stylesheet
+    Css,
+}
+
+impl From<Format> for Mode {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Json => Mode::Json,
+            Format::Yaml => Mode::Yaml,
+            Format::Toml => Mode::Toml,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Style {
+    /// one readable line per offender and a summary
+    Human,
+    /// `file:line:col: message`, for editors and ci annotations
+    Compiler,
+}
+
+/// A word that is not in the expected case.
+#[derive(Debug, PartialEq)]
+pub struct Offender {
+    pub line: usize,
+    pub column: usize,
+    pub word: String,
+    pub case: Case,
+    pub fix: String,
+}
+
+/// A word is in the expected case when converting it changes nothing, so
+/// `hello` passes as camel, snake and kebab alike.
+pub fn check_word(word: &str, expect: &Target) -> Option<(Case, String)> {
+    let core = word.trim_matches(|c| c == '_' || c == '-');
+
+    let expect = match expect {
+        Target::Case(case) => *case,
+        Target::Custom(custom) => {
+            return match custom.split(word) {
+                Some(_) => None,
+                None => Some((get_case(core), custom.join(&split_words(word)))),
+            };
+        }
+    };
+
+    match convert_affixed(word, expect) {
+        Some(fix) if fix == word => None,
+        Some(fix) => Some((get_case(core), fix)),
+        None => Some((Case::None, convert_lenient(word, expect))),
+    }
+}
+
+pub fn find_offenders(input: &str, mode: Mode, expect: &Target) -> Result<Vec<Offender>, String> {
+    let mut offenders = Vec::new();
+
+    let mut push = |line: usize, column: usize, word: &str| {
+        if let Some((case, fix)) = check_word(word, expect) {
+            offenders.push(Offender {
+                line,
+                column,
+                word: word.to_string(),
+                case,
+                fix,
+            });
+        }
+    };
+
+    if mode == Mode::Css {
+        for class in css::find_classes(input) {
+            let (line, column) = position(input, class.start);
+            push(line, column, &input[class]);
+        }
+
+        return Ok(offenders);
+    }
+
+    let format = match mode {
+        Mode::Words | Mode::Lines | Mode::Css => None,
+        Mode::Json => Some(Format::Json),
+        Mode::Yaml => Some(Format::Yaml),
+        Mode::Toml => Some(Format::Toml),
+    };
+
+    if let Some(format) = format {
+        for key in keys::find_keys(input, format)? {
+            let (line, column) = position(input, key.start);
+            push(line, column, &input[key.start..key.end]);
+        }
+
+        return Ok(offenders);
+    }
+
+    for (i, line) in input.lines().enumerate() {
+        if mode == Mode::Lines {
+            let word = line.trim();
+
+            if !word.is_empty() {
+                let column = line[..line.find(word).unwrap()].chars().count() + 1;
+                push(i + 1, column, word);
+            }
+            continue;
+        }
+
+        let mut start: Option<(usize, usize)> = None;
+        let chars = line.char_indices().chain([(line.len(), ' ')]);
+
+        for (column, (offset, c)) in (1..).zip(chars) {
+            match (c.is_whitespace(), start) {
+                (false, None) => start = Some((offset, column)),
+                (true, Some((begin, begin_column))) => {
+                    push(i + 1, begin_column, &line[begin..offset]);
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    Ok(offenders)
+}
+
+pub fn report(file: &str, offenders: &[Offender], expect: &Target, style: Style) {
+    for offender in offenders {
+        let found = match offender.case {
+            Case::None => "has no known case".to_string(),
+            case => format!("is {} case", case),
+        };
+
+        match style {
+            Style::Compiler => println!(
+                "{}:{}:{}: \"{}\" {}, expected {} case: {}",
+                file, offender.line, offender.column, offender.word, found, expect, offender.fix
+            ),
+            Style::Human => println!(
+                "{} line {}, column {}: \"{}\" {}, use \"{}\"",
+                file, offender.line, offender.column, offender.word, found, offender.fix
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_word() {
+        assert_eq!(check_word("hello-world", &Target::Case(Case::Kebab)), None);
+        assert_eq!(check_word("hello", &Target::Case(Case::Kebab)), None);
+        assert_eq!(
+            check_word("_private-field", &Target::Case(Case::Kebab)),
+            None
+        );
+        assert_eq!(
+            check_word("helloWorld", &Target::Case(Case::Kebab)),
+            Some((Case::Camel, "hello-world".to_string()))
+        );
+        assert_eq!(
+            check_word("hello-new_World", &Target::Case(Case::Kebab)),
+            Some((Case::None, "hello-new-world".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check_word_custom() {
+        let key = Target::Custom("key=k_{lower}_{lower}".parse().unwrap());
+
+        assert_eq!(check_word("k_hello_world", &key), None);
+        assert_eq!(
+            check_word("hello_world", &key),
+            Some((Case::Snake, "k_hello_world".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_find_offenders_in_words() {
+        let input = "good-class  badClass\n\n  other_bad ok\n";
+
+        let offenders = find_offenders(input, Mode::Words, &Target::Case(Case::Kebab)).unwrap();
+        let positions: Vec<(usize, usize, &str)> = offenders
+            .iter()
+            .map(|o| (o.line, o.column, o.fix.as_str()))
+            .collect();
+
+        assert_eq!(positions, vec![(1, 13, "bad-class"), (3, 3, "other-bad")]);
+    }
+
+    #[test]
+    fn test_find_offenders_in_lines() {
+        let input = "Hello World\n  helloWorld\n";
+
+        let offenders = find_offenders(input, Mode::Lines, &Target::Case(Case::Title)).unwrap();
+
+        assert_eq!(
+            offenders,
+            vec![Offender {
+                line: 2,
+                column: 3,
+                word: "helloWorld".to_string(),
+                case: Case::Camel,
+                fix: "Hello World".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_offenders_in_json() {
+        let input = "{\n  \"user_name\": \"x\",\n  \"nested\": { \"zipCode\": 1 }\n}\n";
+
+        let offenders = find_offenders(input, Mode::Json, &Target::Case(Case::Snake)).unwrap();
+
+        assert_eq!(offenders.len(), 1);
+        assert_eq!((offenders[0].line, offenders[0].column), (3, 16));
+        assert_eq!(offenders[0].fix, "zip_code");
+    }
+
+    #[test]
+    fn test_find_offenders_in_yaml() {
+        let input = "good-key: 1\nnested:\n  badKey: 2\n";
+
+        assert_eq!(
+            find_offenders(input, Mode::Yaml, &Target::Case(Case::Kebab)).unwrap(),
+            vec![Offender {
+                line: 3,
+                column: 3,
+                word: "badKey".to_string(),
+                case: Case::Camel,
+                fix: "bad-key".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_offenders_in_css() {
+        let input = ".nav-bar { margin: 0.5em; }\n\n.navBar > .item_link { }\n";
+
+        let offenders = find_offenders(input, Mode::Css, &Target::Case(Case::Kebab)).unwrap();
+        let positions: Vec<(usize, usize, &str)> = offenders
+            .iter()
+            .map(|o| (o.line, o.column, o.fix.as_str()))
+            .collect();
+
+        assert_eq!(positions, vec![(3, 2, "nav-bar"), (3, 12, "item-link")]);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::keys::is_bare_key;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+pub const FILE_NAME: &str = ".wcase.toml";
+
+/// Top level keys and the type of their values.
+const KEYS: &[(&str, &str)] = &[
+    ("case", "string"),
+    ("lang", "string"),
+    ("escape", "string"),
+    ("ascii", "boolean"),
+    ("sanitize", "boolean"),
+    ("leading-digit", "string"),
+    ("digit-prefix", "string"),
+    ("segment", "boolean"),
+    ("dict", "string"),
+    ("max-len", "integer"),
+    ("acronyms", "array"),
+];
+
+/// Tables of string values, merged key by key.
+const TABLES: &[&str] = &["cases", "abbreviations"];
+
+/// A value and the file it was read from.
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub value: Value,
+    pub source: PathBuf,
+}
+
+/// Settings merged from every configuration file found. Keys of tables are
+/// stored as `table.key`.
+#[derive(Debug, Default)]
+pub struct Config {
+    entries: BTreeMap<String, Entry>,
+}
+
+/// Configuration files from the most to the least specific: `.wcase.toml` in
+/// the current directory and its parents up to home, then the user config.
+pub fn find_files(cwd: &Path, home: Option<&Path>, xdg_config: Option<&Path>) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for dir in cwd.ancestors() {
+        let file = dir.join(FILE_NAME);
+
+        if file.is_file() {
+            files.push(file);
+        }
+
+        if Some(dir) == home {
+            break;
+        }
+    }
+
+    let user = match (xdg_config, home) {
+        (Some(xdg_config), _) => Some(xdg_config.to_path_buf()),
+        (None, Some(home)) => Some(home.join(".config")),
+        (None, None) => None,
+    };
+
+    if let Some(file) = user.map(|dir| dir.join("wcase").join("config.toml")) {
+        if file.is_file() {
+            files.push(file);
+        }
+    }
+
+    files
+}
+
+impl Config {
+    /// Loads the configuration files of the current directory.
+    pub fn load() -> Result<Config, String> {
+        let cwd =
+            env::current_dir().map_err(|e| format!("Could not read current directory: {}", e))?;
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let xdg_config = env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+
+        let mut config = Config::default();
+
+        for file in find_files(&cwd, home.as_deref(), xdg_config.as_deref())
+            .iter()
+            .rev()
+        {
+            let text = fs::read_to_string(file)
+                .map_err(|e| format!("Could not read {}: {}", file.display(), e))?;
+            config.merge(&text, file)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Adds the settings of a file over the ones already loaded.
+    pub fn merge(&mut self, text: &str, source: &Path) -> Result<(), String> {
+        let error = |msg: String| format!("{}: {}", source.display(), msg);
+
+        let table: toml::Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| error(e.message().to_string()))?;
+
+        for (key, value) in table {
+            let mut entries = vec![];
+
+            if TABLES.contains(&key.as_str()) {
+                let table = match value {
+                    Value::Table(table) => table,
+                    _ => return Err(error(format!("\"{}\" must be a table", key))),
+                };
+
+                for (name, value) in table {
+                    if !value.is_str() {
+                        return Err(error(format!("\"{}.{}\" must be a string", key, name)));
+                    }
+
+                    entries.push((format!("{}.{}", key, name), value));
+                }
+            } else {
+                let expected = match KEYS.iter().find(|(name, _)| *name == key) {
+                    Some((_, expected)) => *expected,
+                    None => return Err(error(format!("Unknown key \"{}\"", key))),
+                };
+
+                let is_valid = match expected {
+                    "array" => value
+                        .as_array()
+                        .is_some_and(|array| array.iter().all(Value::is_str)),
+                    "integer" => value.as_integer().is_some_and(|n| n > 0),
+                    _ => value.type_str() == expected,
+                };
+
+                if !is_valid {
+                    let expected = match expected {
+                        "array" => "an array of strings",
+                        "integer" => "a positive integer",
+                        "boolean" => "a boolean",
+                        _ => "a string",
+                    };
+
+                    return Err(error(format!("\"{}\" must be {}", key, expected)));
+                }
+
+                entries.push((key, value));
+            }
+
+            for (key, value) in entries {
+                let source = source.to_path_buf();
+                self.entries.insert(key, Entry { value, source });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.get(key)
+    }
+
+    pub fn string(&self, key: &str) -> Option<String> {
+        self.get(key)?.value.as_str().map(String::from)
+    }
+
+    pub fn boolean(&self, key: &str) -> bool {
+        self.get(key).and_then(|entry| entry.value.as_bool()) == Some(true)
+    }
+
+    pub fn integer(&self, key: &str) -> Option<usize> {
+        self.get(key)?.value.as_integer().map(|n| n as usize)
+    }
+
+    pub fn strings(&self, key: &str) -> Vec<String> {
+        match self.get(key).and_then(|entry| entry.value.as_array()) {
+            Some(array) => array
+                .iter()
+                .filter_map(|value| value.as_str().map(String::from))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// A path, relative to the file it was read from.
+    pub fn path(&self, key: &str) -> Option<PathBuf> {
+        let entry = self.get(key)?;
+        let path = Path::new(entry.value.as_str()?);
+        let dir = entry.source.parent().unwrap_or(Path::new(""));
+
+        Some(dir.join(path))
+    }
+
+    /// The `(key, value)` pairs of a table.
+    pub fn table(&self, table: &str) -> Vec<(String, String)> {
+        let prefix = format!("{}.", table);
+
+        self.entries
+            .iter()
+            .filter_map(|(key, entry)| {
+                let name = key.strip_prefix(&prefix)?;
+                Some((name.to_string(), entry.value.as_str()?.to_string()))
+            })
+            .collect()
+    }
+
+    /// Every setting with the file it comes from, one per line.
+    pub fn show(&self) -> String {
+        self.entries
+            .iter()
+            .map(|(key, entry)| {
+                let key = match key.split_once('.') {
+                    Some((table, name)) if !is_bare_key(name) => format!("{}.{:?}", table, name),
+                    _ => key.to_string(),
+                };
+
+                format!("{} = {}  # {}\n", key, entry.value, entry.source.display())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let mut config = Config::default();
+
+        config
+            .merge(
+                "case = \"snake\"\nascii = true\ndict = \"words.txt\"\n\n[cases]\nkey = \"k_{lower}_{lower}\"\n",
+                Path::new("/home/me/.config/wcase/config.toml"),
+            )
+            .unwrap();
+        config
+            .merge(
+                "case = \"kebab\"\nacronyms = [\"ID\"]\n\n[cases]\nmember = \"m_{lower}{capital}\"\n",
+                Path::new("/home/me/project/.wcase.toml"),
+            )
+            .unwrap();
+
+        assert_eq!(config.string("case").unwrap(), "kebab");
+        assert!(config.boolean("ascii"));
+        assert!(!config.boolean("segment"));
+        assert_eq!(config.strings("acronyms"), vec!["ID"]);
+        assert_eq!(
+            config.path("dict").unwrap(),
+            Path::new("/home/me/.config/wcase/words.txt")
+        );
+        assert_eq!(config.table("cases").len(), 2);
+        assert_eq!(
+            config.show().lines().next().unwrap(),
+            "acronyms = [\"ID\"]  # /home/me/project/.wcase.toml"
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let source = Path::new(".wcase.toml");
+        let error = |text: &str| Config::default().merge(text, source).unwrap_err();
+
+        assert_eq!(error("colour = 1"), ".wcase.toml: Unknown key \"colour\"");
+        assert_eq!(
+            error("ascii = \"yes\""),
+            ".wcase.toml: \"ascii\" must be a boolean"
+        );
+        assert_eq!(
+            error("max-len = 0"),
+            ".wcase.toml: \"max-len\" must be a positive integer"
+        );
+        assert_eq!(
+            error("[cases]\nkey = 1"),
+            ".wcase.toml: \"cases.key\" must be a string"
+        );
+    }
+
+    #[test]
+    fn test_find_files() {
+        let root = env::temp_dir().join(format!("wcase-config-{}", std::process::id()));
+        let home = root.join("home");
+        let project = home.join("project").join("src");
+
+        fs::create_dir_all(&project).unwrap();
+        fs::create_dir_all(home.join(".config").join("wcase")).unwrap();
+        fs::write(root.join(FILE_NAME), "").unwrap();
+        fs::write(home.join(FILE_NAME), "").unwrap();
+        fs::write(home.join("project").join(FILE_NAME), "").unwrap();
+        fs::write(home.join(".config").join("wcase").join("config.toml"), "").unwrap();
+
+        let files = find_files(&project, Some(&home), None);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                home.join("project").join(FILE_NAME),
+                home.join(FILE_NAME),
+                home.join(".config").join("wcase").join("config.toml"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::ops::Range;
+
+/// At-rules whose blocks hold rules, the others hold declarations.
+const GROUPING_RULES: [&str; 6] = [
+    "media",
+    "supports",
+    "layer",
+    "container",
+    "document",
+    "scope",
+];
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b >= 0x80
+}
+
+/// Index after the string starting at `start`, or the end of the input when
+/// it is not closed.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Index after the class name starting at `start`, escapes included.
+fn skip_name(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if i + 1 < bytes.len() => i += 2,
+            b if is_name_byte(b) => i += 1,
+            _ => break,
+        }
+    }
+
+    i.min(bytes.len())
+}
+
+/// Byte ranges of the class names of every selector, without their dot.
+/// Comments, strings, declarations and the preludes of at-rules are skipped.
+pub fn find_classes(input: &str) -> Vec<Range<usize>> {
+    let bytes = input.as_bytes();
+    let mut classes = Vec::new();
+    // whether each open block holds rules, as the top level does
+    let mut blocks = vec![true];
+    // name of the at-rule whose prelude is being read
+    let mut at_rule: Option<&str> = None;
+    let mut prelude_is_empty = true;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let in_rules = *blocks.last().unwrap();
+
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = match input[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 2,
+                    None => bytes.len(),
+                };
+                continue;
+            }
+            b'"' | b'\'' => {
+                i = skip_string(bytes, i);
+                prelude_is_empty = false;
+                continue;
+            }
+            b'{' => {
+                let groups = at_rule.is_some_and(|name| GROUPING_RULES.contains(&name));
+                blocks.push(in_rules && groups);
+                at_rule = None;
+                prelude_is_empty = true;
+            }
+            b'}' => {
+                if blocks.len() > 1 {
+                    blocks.pop();
+                }
+                at_rule = None;
+                prelude_is_empty = true;
+            }
+            b';' => {
+                at_rule = None;
+                prelude_is_empty = true;
+            }
+            b'@' if in_rules && prelude_is_empty => {
+                let end = skip_name(bytes, i + 1);
+                at_rule = Some(&input[i + 1..end]);
+                prelude_is_empty = false;
+                i = end;
+                continue;
+            }
+            b'.' if in_rules && at_rule.is_none() => {
+                let start = i + 1;
+                let end = skip_name(bytes, start);
+                // names don't start with a digit, which also skips numbers
+                let is_name = match bytes.get(start) {
+                    Some(b'-') => end > start + 1,
+                    Some(&b) => b == b'\\' || is_name_byte(b) && !b.is_ascii_digit(),
+                    None => false,
+                };
+
+                if is_name {
+                    classes.push(start..end);
+                }
+
+                prelude_is_empty = false;
+                i = end;
+                continue;
+            }
+            b if !b.is_ascii_whitespace() => prelude_is_empty = false,
+            _ => (),
+        }
+
+        i += 1;
+    }
+
+    classes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(input: &str) -> Vec<&str> {
+        find_classes(input)
+            .into_iter()
+            .map(|range| &input[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_find_classes() {
+        let input = "\
+/* .commented-out */
+.nav-bar, div.menuItem > .item_link:hover { margin: 0.5em; content: \".not-a-class\"; }
+@import url(\"theme.css\");
+@media (min-width: 40.5em) {
+  .wideLayout .sidebar { width: 1.5rem; }
+}
+@keyframes fade { 50.5% { opacity: .5; } }
+a[href$=\".pdf\"]:not(.is-external) { color: red; }
+";
+
+        assert_eq!(
+            classes(input),
+            vec![
+                "nav-bar",
+                "menuItem",
+                "item_link",
+                "wideLayout",
+                "sidebar",
+                "is-external"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_classes_with_escapes() {
+        assert_eq!(classes(".md\\:flex { }"), vec!["md\\:flex"]);
+        assert_eq!(classes(".-négatif { }"), vec!["-négatif"]);
+    }
+}
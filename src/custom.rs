@@ -0,0 +1,291 @@
+use crate::{
+    capitalize_first_letter, convert_affixed, convert_lenient, get_case, join_words, split_words,
+    Case,
+};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    Lower,
+    Upper,
+    Capital,
+}
+
+impl Transform {
+    fn apply(&self, word: &str) -> String {
+        match self {
+            Transform::Lower => word.to_lowercase(),
+            Transform::Upper => word.to_uppercase(),
+            Transform::Capital => capitalize_first_letter(&word.to_lowercase()),
+        }
+    }
+}
+
+impl FromStr for Transform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lower" => Ok(Transform::Lower),
+            "upper" => Ok(Transform::Upper),
+            "capital" => Ok(Transform::Capital),
+            _ => Err(format!(
+                "Invalid word transform {{{}}}, use {{lower}}, {{upper}} or {{capital}}",
+                s
+            )),
+        }
+    }
+}
+
+/// A case defined by a template like `k_{lower}_{lower}`: the first word, the
+/// separator and the rest of the words, between a prefix and a suffix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomCase {
+    pub name: String,
+    prefix: String,
+    separator: String,
+    suffix: String,
+    first: Transform,
+    rest: Transform,
+}
+
+impl CustomCase {
+    pub fn new(name: &str, template: &str) -> Result<CustomCase, String> {
+        if Case::from_str(name).is_ok() {
+            return Err(format!("\"{}\" is already a built in case", name));
+        }
+
+        let invalid = || {
+            format!(
+                "Invalid template \"{}\", use two word transforms like \"k_{{lower}}_{{lower}}\"",
+                template
+            )
+        };
+
+        let mut parts = Vec::new();
+        let mut rest = template;
+
+        while let Some(open) = rest.find('{') {
+            let close = rest[open..].find('}').ok_or_else(invalid)? + open;
+            parts.push((&rest[..open], rest[open + 1..close].parse::<Transform>()?));
+            rest = &rest[close + 1..];
+        }
+
+        match parts[..] {
+            [(prefix, first), (separator, rest_transform)] => Ok(CustomCase {
+                name: name.to_string(),
+                prefix: prefix.to_string(),
+                separator: separator.to_string(),
+                suffix: rest.to_string(),
+                first,
+                rest: rest_transform,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+
+    pub fn join(&self, words: &[String]) -> String {
+        let words: Vec<String> = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| match i {
+                0 => self.first.apply(word),
+                _ => self.rest.apply(word),
+            })
+            .collect();
+
+        format!(
+            "{}{}{}",
+            self.prefix,
+            words.join(&self.separator),
+            self.suffix
+        )
+    }
+
+    /// Splits a word written in this case, or returns `None` when it is not.
+    pub fn split(&self, word: &str) -> Option<Vec<String>> {
+        let body = word
+            .strip_prefix(&self.prefix)?
+            .strip_suffix(&self.suffix)?;
+
+        let words: Vec<String> = if self.separator.is_empty() {
+            split_words(body)
+        } else {
+            body.split(&self.separator).map(String::from).collect()
+        };
+
+        let is_word = |word: &String| !word.is_empty() && word.chars().all(char::is_alphanumeric);
+
+        if !words.iter().all(is_word) || self.join(&words) != word {
+            return None;
+        }
+
+        Some(words)
+    }
+}
+
+/// Parses `NAME=TEMPLATE`, as given to `--define`.
+impl FromStr for CustomCase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, template)) if !name.is_empty() => CustomCase::new(name, template),
+            _ => Err(format!(
+                "Invalid case definition \"{}\", use NAME=TEMPLATE",
+                s
+            )),
+        }
+    }
+}
+
+/// A case to convert to, built in or defined by the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Case(Case),
+    Custom(CustomCase),
+}
+
+impl Target {
+    /// Finds a case by name, built in cases first.
+    pub fn resolve(name: &str, custom: &[CustomCase]) -> Result<Target, String> {
+        if let Ok(case) = Case::from_str(name) {
+            return Ok(Target::Case(case));
+        }
+
+        custom
+            .iter()
+            .find(|case| case.name == name)
+            .map(|case| Target::Custom(case.clone()))
+            .ok_or_else(|| format!("Unknown case \"{}\"", name))
+    }
+
+    pub fn join(&self, words: &[String]) -> String {
+        match self {
+            Target::Case(case) => join_words(words, *case),
+            Target::Custom(custom) => custom.join(words),
+        }
+    }
+
+    /// Converts a word like `convert_affixed`, keeping its leading and
+    /// trailing separators. Returns `None` when the case is unknown.
+    pub fn convert_affixed(&self, word: &str) -> Option<String> {
+        let custom = match self {
+            Target::Case(case) => return convert_affixed(word, *case),
+            Target::Custom(custom) => custom,
+        };
+
+        let core = word.trim_matches(|c| c == '_' || c == '-');
+
+        if core.is_empty() {
+            return Some(word.to_string());
+        }
+
+        if get_case(core) == Case::None {
+            return None;
+        }
+
+        let start = word.find(core).unwrap_or(0);
+        let prefix = &word[..start];
+        let suffix = &word[start + core.len()..];
+
+        Some(format!(
+            "{}{}{}",
+            prefix,
+            custom.join(&split_words(core)),
+            suffix
+        ))
+    }
+
+    /// Converts any text through `split_words`, like `convert_lenient`.
+    pub fn convert_lenient(&self, text: &str) -> String {
+        match self {
+            Target::Case(case) => convert_lenient(text, *case),
+            Target::Custom(custom) => custom.join(&split_words(text)),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Target::Case(case) => write!(f, "{}", case),
+            Target::Custom(custom) => write!(f, "{}", custom.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn words(text: &str) -> Vec<String> {
+        split_words(text)
+    }
+
+    #[test]
+    fn test_join() {
+        let key: CustomCase = "key=k_{lower}_{lower}".parse().unwrap();
+        let member: CustomCase = "member=m_{lower}{capital}".parse().unwrap();
+        let double: CustomCase = "double={upper}__{upper}".parse().unwrap();
+
+        assert_eq!(key.join(&words("hello world")), "k_hello_world");
+        assert_eq!(member.join(&words("hello world")), "m_helloWorld");
+        assert_eq!(double.join(&words("helloWorld")), "HELLO__WORLD");
+    }
+
+    #[test]
+    fn test_split() {
+        let member: CustomCase = "member=m_{lower}{capital}".parse().unwrap();
+        let double: CustomCase = "double={upper}__{upper}".parse().unwrap();
+
+        assert_eq!(member.split("m_helloWorld"), Some(words("hello World")));
+        assert_eq!(member.split("m_hello_world"), None);
+        assert_eq!(member.split("helloWorld"), None);
+        assert_eq!(double.split("HELLO__WORLD"), Some(words("HELLO WORLD")));
+        assert_eq!(double.split("HELLO_WORLD"), None);
+    }
+
+    #[test]
+    fn test_invalid_definitions() {
+        assert!("snake={lower}_{lower}".parse::<CustomCase>().is_err());
+        assert!("key=k_{lower}".parse::<CustomCase>().is_err());
+        assert!("key={lower}_{shout}".parse::<CustomCase>().is_err());
+        assert!("k_{lower}_{lower}".parse::<CustomCase>().is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let custom = vec!["key=k_{lower}_{lower}".parse::<CustomCase>().unwrap()];
+
+        assert_eq!(
+            Target::resolve("all-caps", &custom),
+            Ok(Target::Case(Case::AllCaps))
+        );
+        assert_eq!(Target::resolve("key", &custom).unwrap().to_string(), "key");
+        assert!(Target::resolve("other", &custom).is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        let key = Target::Custom("key=k_{lower}_{lower}".parse().unwrap());
+
+        assert_eq!(
+            key.convert_affixed("__userName").as_deref(),
+            Some("__k_user_name")
+        );
+        assert_eq!(key.convert_affixed("user Name"), None);
+        assert_eq!(key.convert_lenient("User-name"), "k_user_name");
+        assert_eq!(
+            Target::Case(Case::Snake)
+                .convert_affixed("_userName")
+                .as_deref(),
+            Some("_user_name")
+        );
+    }
+}
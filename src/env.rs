@@ -0,0 +1,224 @@
+use crate::custom::Target;
+use crate::{convert, convert_lenient, get_case, Case};
+
+pub struct Options {
+    pub prefix: Option<String>,
+    pub separator: String,
+}
+
+impl Options {
+    fn prefix(&self) -> Option<String> {
+        self.prefix.as_ref().map(|prefix| {
+            format!(
+                "{}_",
+                convert(prefix, get_case(prefix), Case::AllCaps).trim_end_matches('_')
+            )
+        })
+    }
+}
+
+/// `database.pool.maxSize` -> `APP_DATABASE__POOL__MAX_SIZE`
+pub fn to_env_name(key: &str, options: &Options) -> String {
+    let segments: Vec<String> = key
+        .split('.')
+        .map(|segment| match get_case(segment) {
+            Case::None => convert_lenient(segment, Case::AllCaps),
+            case => convert(segment, case, Case::AllCaps),
+        })
+        .collect();
+
+    format!(
+        "{}{}",
+        options.prefix().unwrap_or_default(),
+        segments.join(&options.separator)
+    )
+}
+
+/// `APP_DATABASE__POOL__MAX_SIZE` -> `database.pool.maxSize`. Fails when the
+/// name doesn't start with the prefix or has nothing after it. Empty
+/// segments, as in `APP_POOL____SIZE__`, are skipped.
+pub fn from_env_name(name: &str, to: &Target, options: &Options) -> Result<String, String> {
+    let rest = match options.prefix() {
+        Some(prefix) => name
+            .strip_prefix(&prefix)
+            .ok_or_else(|| format!("\"{}\" doesn't start with the prefix", name))?,
+        None => name,
+    };
+
+    let segments: Vec<String> = rest
+        .split(options.separator.as_str())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match (to, get_case(segment)) {
+            (Target::Case(to), case) if case != Case::None => convert(segment, case, *to),
+            _ => to.convert_lenient(segment),
+        })
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if segments.is_empty() {
+        return Err(format!("\"{}\" has no key after the prefix", name));
+    }
+
+    Ok(segments.join("."))
+}
+
+/// Rewrites the keys of a `.env` file through `rename`, keeping `export`,
+/// values, quoting and comments as they are. Keys `rename` returns `None`
+/// for are left untouched.
+pub fn rewrite(input: &str, rename: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut open_quote: Option<char> = None;
+
+    for line in input.split_inclusive('\n') {
+        if let Some(quote) = open_quote {
+            result.push_str(line);
+
+            if closes_quote(line, quote) {
+                open_quote = None;
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+
+        let equals = match trimmed.find('=') {
+            Some(equals) if !trimmed.starts_with('#') => equals,
+            _ => {
+                result.push_str(line);
+                continue;
+            }
+        };
+
+        let indent = &line[..line.len() - trimmed.len()];
+        let (export, declaration) = match trimmed.strip_prefix("export ") {
+            Some(rest) => ("export ", rest),
+            None => ("", trimmed),
+        };
+
+        let equals = equals - export.len();
+        let key = declaration[..equals].trim();
+        let key_start = declaration.find(key).unwrap_or(0);
+        let value = &declaration[equals + 1..];
+
+        if key.is_empty() {
+            result.push_str(line);
+            continue;
+        }
+
+        result.push_str(indent);
+        result.push_str(export);
+        result.push_str(&declaration[..key_start]);
+        result.push_str(&rename(key).unwrap_or_else(|| key.to_string()));
+        result.push_str(&declaration[key_start + key.len()..equals + 1]);
+        result.push_str(value);
+
+        let value = value.trim_start();
+        if let Some(quote @ ('"' | '\'')) = value.chars().next() {
+            if !closes_quote(&value[1..], quote) {
+                open_quote = Some(quote);
+            }
+        }
+    }
+
+    result
+}
+
+fn closes_quote(text: &str, quote: char) -> bool {
+    let mut escaped = false;
+
+    for c in text.chars() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote == '"' {
+            escaped = true;
+        } else if c == quote {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Options {
+        Options {
+            prefix: Some("app".to_string()),
+            separator: "__".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_to_env_name() {
+        assert_eq!(
+            to_env_name("database.pool.maxSize", &options()),
+            "APP_DATABASE__POOL__MAX_SIZE"
+        );
+
+        let options = Options {
+            prefix: None,
+            separator: "_".to_string(),
+        };
+        assert_eq!(
+            to_env_name("server.http-port", &options),
+            "SERVER_HTTP_PORT"
+        );
+    }
+
+    #[test]
+    fn test_from_env_name() {
+        assert_eq!(
+            from_env_name(
+                "APP_DATABASE__POOL__MAX_SIZE",
+                &Target::Case(Case::Camel),
+                &options()
+            ),
+            Ok("database.pool.maxSize".to_string())
+        );
+        assert_eq!(
+            from_env_name("HOME", &Target::Case(Case::Camel), &options()),
+            Err("\"HOME\" doesn't start with the prefix".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_env_name_skips_empty_segments() {
+        assert_eq!(
+            from_env_name("APP_FOO__", &Target::Case(Case::Pascal), &options()),
+            Ok("Foo".to_string())
+        );
+        assert_eq!(
+            from_env_name(
+                "APP_POOL_____MAX_SIZE",
+                &Target::Case(Case::Pascal),
+                &options()
+            ),
+            Ok("Pool.MaxSize".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_env_name_of_the_prefix() {
+        assert_eq!(
+            from_env_name("APP_", &Target::Case(Case::Pascal), &options()),
+            Err("\"APP_\" has no key after the prefix".to_string())
+        );
+        assert_eq!(
+            from_env_name("APP___", &Target::Case(Case::Pascal), &options()),
+            Err("\"APP___\" has no key after the prefix".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let input = "# database_settings\ndatabase.pool.maxSize=10 # inline\nexport server.port = \"80\"\nmulti.lineKey=\"first\nnot.a_key=second\"\n\n";
+        let expected = "# database_settings\nAPP_DATABASE__POOL__MAX_SIZE=10 # inline\nexport APP_SERVER__PORT = \"80\"\nAPP_MULTI__LINE_KEY=\"first\nnot.a_key=second\"\n\n";
+
+        assert_eq!(
+            rewrite(input, |key| Some(to_env_name(key, &options()))),
+            expected
+        );
+    }
+}
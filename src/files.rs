@@ -0,0 +1,221 @@
+use crate::custom::Target;
+use crate::lang::{Escape, Language};
+use crate::lexer::{tokenize, TokenKind};
+use crate::scanner::position;
+use crate::{get_case, Case};
+
+pub struct Options {
+    pub lang: Language,
+    pub from: Case,
+    pub to: Target,
+    pub strings: bool,
+    pub comments: bool,
+    /// escapes new names that are keywords, instead of keeping the old name
+    pub escape: Option<Escape>,
+}
+
+/// An identifier whose new name is a keyword. It is left alone unless an
+/// escape was given.
+#[derive(Debug, PartialEq)]
+pub struct Collision {
+    pub line: usize,
+    pub column: usize,
+    pub word: String,
+    pub keyword: String,
+    pub escaped: Option<String>,
+}
+
+impl Options {
+    fn rename(&self, word: &str) -> Option<String> {
+        let core = word.trim_matches(|c| c == '_' || c == '-');
+
+        if core.is_empty() || get_case(core) != self.from {
+            return None;
+        }
+
+        self.to
+            .convert_affixed(word)
+            .filter(|new_word| new_word != word)
+    }
+
+    fn is_word_char(&self, c: char) -> bool {
+        c.is_alphanumeric()
+            || c == '_'
+            || c == '-' && matches!(self.from, Case::Kebab | Case::Train | Case::HttpHeader)
+    }
+}
+
+/// Renames every identifier in `from` case, and optionally the words inside
+/// strings and comments, leaving keywords and everything else untouched.
+pub fn rewrite(src: &str, options: &Options) -> (String, Vec<Collision>) {
+    let mut result = String::with_capacity(src.len());
+    let mut collisions = Vec::new();
+    let mut last = 0;
+
+    for token in tokenize(src, options.lang) {
+        let text = &src[token.start..token.end];
+
+        match token.kind {
+            TokenKind::Identifier => {
+                let mut new_word = match options.rename(text) {
+                    Some(new_word) => new_word,
+                    None => continue,
+                };
+
+                if options.lang.is_keyword(&new_word) {
+                    let (line, column) = position(src, token.start);
+                    let escaped = options
+                        .escape
+                        .as_ref()
+                        .and_then(|escape| options.lang.escape(&new_word, escape));
+
+                    collisions.push(Collision {
+                        line,
+                        column,
+                        word: text.to_string(),
+                        keyword: new_word,
+                        escaped: escaped.clone(),
+                    });
+
+                    new_word = match escaped {
+                        Some(escaped) => escaped,
+                        None => continue,
+                    };
+                }
+
+                result.push_str(&src[last..token.start]);
+                result.push_str(&new_word);
+                last = token.end;
+            }
+            TokenKind::String | TokenKind::Comment => {
+                let enabled = match token.kind {
+                    TokenKind::String => options.strings,
+                    _ => options.comments,
+                };
+
+                if !enabled {
+                    continue;
+                }
+
+                result.push_str(&src[last..token.start]);
+                result.push_str(&rewrite_text(text, options));
+                last = token.end;
+            }
+            TokenKind::Keyword => (),
+        }
+    }
+
+    result.push_str(&src[last..]);
+
+    (result, collisions)
+}
+
+/// Renames the words of free text, as found in strings and comments.
+fn rewrite_text(text: &str, options: &Options) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word = String::new();
+
+    for c in text.chars().chain(['\0']) {
+        if options.is_word_char(c) {
+            word.push(c);
+            continue;
+        }
+
+        if !word.is_empty() {
+            result.push_str(&options.rename(&word).unwrap_or(word));
+            word = String::new();
+        }
+
+        if c != '\0' {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(lang: Language, from: Case, to: Case) -> Options {
+        Options {
+            lang,
+            from,
+            to: Target::Case(to),
+            strings: false,
+            comments: false,
+            escape: None,
+        }
+    }
+
+    #[test]
+    fn test_rewrite_identifiers() {
+        let src = "let userName = getUser(\"userName\"); // userName\nif (userName) return;";
+        let expected =
+            "let user_name = get_user(\"userName\"); // userName\nif (user_name) return;";
+
+        let options = options(Language::Javascript, Case::Camel, Case::Snake);
+
+        assert_eq!(rewrite(src, &options), (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn test_rewrite_strings_and_comments() {
+        let src = "user_name = get(\"user_name\")  # keep user_name, not other-word\n";
+        let expected = "userName = get(\"userName\")  # keep userName, not other-word\n";
+
+        let mut options = options(Language::Python, Case::Snake, Case::Camel);
+        options.strings = true;
+        options.comments = true;
+
+        assert_eq!(rewrite(src, &options).0, expected);
+    }
+
+    #[test]
+    fn test_rewrite_interpolations() {
+        let src = "user_name = 1\nprint(f\"{user_name!r:>{max_len}} user_name\")\n";
+        let expected = "userName = 1\nprint(f\"{userName!r:>{maxLen}} user_name\")\n";
+
+        let python = options(Language::Python, Case::Snake, Case::Camel);
+        assert_eq!(rewrite(src, &python).0, expected);
+
+        let src = "const user_name = `${user_name} ${`${get_id()}`}`;";
+        let expected = "const userName = `${userName} ${`${getId()}`}`;";
+
+        let javascript = options(Language::Javascript, Case::Snake, Case::Camel);
+        assert_eq!(rewrite(src, &javascript).0, expected);
+    }
+
+    #[test]
+    fn test_keywords_are_skipped() {
+        let src = "struct Type;\nfn new(SelfRef: Type) {}";
+
+        let options = options(Language::Rust, Case::Pascal, Case::Snake);
+        let (output, skipped) = rewrite(src, &options);
+
+        assert_eq!(output, "struct Type;\nfn new(self_ref: Type) {}");
+        assert_eq!(
+            skipped
+                .iter()
+                .map(|s| (s.line, s.column))
+                .collect::<Vec<_>>(),
+            vec![(1, 8), (2, 17)]
+        );
+        assert_eq!(skipped[0].keyword, "type");
+        assert_eq!(skipped[0].escaped, None);
+    }
+
+    #[test]
+    fn test_keywords_are_escaped() {
+        let src = "struct Type;\nfn new(MatchRef: Type) {}";
+
+        let mut options = options(Language::Rust, Case::Pascal, Case::Snake);
+        options.escape = Some(Escape::Raw);
+        let (output, escaped) = rewrite(src, &options);
+
+        assert_eq!(output, "struct r#type;\nfn new(match_ref: r#type) {}");
+        assert_eq!(escaped.len(), 2);
+        assert_eq!(escaped[1].escaped.as_deref(), Some("r#type"));
+    }
+}
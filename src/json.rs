@@ -0,0 +1,256 @@
+use crate::custom::Target;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Options {
+    pub to: Target,
+    pub include: Vec<Vec<String>>,
+    pub exclude: Vec<Vec<String>>,
+    pub values: bool,
+    pub strict: bool,
+}
+
+impl Options {
+    fn is_selected(&self, path: &[String]) -> bool {
+        let included =
+            self.include.is_empty() || self.include.iter().any(|pattern| is_prefix(pattern, path));
+
+        included && !self.exclude.iter().any(|pattern| is_prefix(pattern, path))
+    }
+}
+
+pub fn parse_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    match pointer.strip_prefix('/') {
+        Some(rest) => Ok(rest
+            .split('/')
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+            .collect()),
+        None => Err(format!(
+            "Invalid path \"{}\", expected a json pointer like /data/items",
+            pointer
+        )),
+    }
+}
+
+fn to_pointer(path: &[String]) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+
+    path.iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+fn is_prefix(pattern: &[String], path: &[String]) -> bool {
+    pattern.len() <= path.len()
+        && pattern
+            .iter()
+            .zip(path)
+            .all(|(expected, segment)| expected == "*" || expected == segment)
+}
+
+/// Two keys of an object that transform to the same key. Without `strict`
+/// the value of the last one is kept.
+#[derive(Debug, PartialEq)]
+pub struct Collision {
+    pub path: String,
+    pub first: String,
+    pub second: String,
+    pub key: String,
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Key collision at {}: \"{}\" and \"{}\" both become \"{}\"",
+            self.path, self.first, self.second, self.key
+        )
+    }
+}
+
+/// Transforms the keys, with the collisions found. Fails on the first
+/// collision with `strict`.
+pub fn transform(value: Value, options: &Options) -> Result<(Value, Vec<Collision>), String> {
+    let mut collisions = Vec::new();
+    let value = transform_value(value, &mut Vec::new(), options, &mut collisions)?;
+    Ok((value, collisions))
+}
+
+fn transform_value(
+    value: Value,
+    path: &mut Vec<String>,
+    options: &Options,
+    collisions: &mut Vec<Collision>,
+) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => Ok(Value::Object(transform_object(
+            map, path, options, collisions,
+        )?)),
+        Value::Array(items) => {
+            let mut result = Vec::with_capacity(items.len());
+
+            for (index, item) in items.into_iter().enumerate() {
+                path.push(index.to_string());
+                result.push(transform_value(item, path, options, collisions)?);
+                path.pop();
+            }
+
+            Ok(Value::Array(result))
+        }
+        Value::String(text) if options.values && options.is_selected(path) => Ok(Value::String(
+            options.to.convert_affixed(&text).unwrap_or(text),
+        )),
+        other => Ok(other),
+    }
+}
+
+fn transform_object(
+    map: Map<String, Value>,
+    path: &mut Vec<String>,
+    options: &Options,
+    collisions: &mut Vec<Collision>,
+) -> Result<Map<String, Value>, String> {
+    let mut result = Map::new();
+    let mut originals: HashMap<String, String> = HashMap::new();
+
+    for (key, value) in map {
+        path.push(key.clone());
+        let selected = options.is_selected(path);
+        let value = transform_value(value, path, options, collisions)?;
+        path.pop();
+
+        let new_key = if selected {
+            options
+                .to
+                .convert_affixed(&key)
+                .unwrap_or_else(|| key.clone())
+        } else {
+            key.clone()
+        };
+
+        if let Some(previous) = originals.insert(new_key.clone(), key.clone()) {
+            let collision = Collision {
+                path: to_pointer(path),
+                first: previous,
+                second: key,
+                key: new_key.clone(),
+            };
+
+            if options.strict {
+                return Err(collision.to_string());
+            }
+
+            collisions.push(collision);
+        }
+
+        result.insert(new_key, value);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Case;
+    use serde_json::json;
+
+    fn options(to: Case) -> Options {
+        Options {
+            to: Target::Case(to),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            values: false,
+            strict: false,
+        }
+    }
+
+    #[test]
+    fn test_transform_keys() {
+        let input = json!({
+            "user_name": "john_doe",
+            "_id": 1,
+            "home_address": { "zip_code": "1234", "tags": [{ "tag_name": "a" }] },
+        });
+
+        let expected = json!({
+            "userName": "john_doe",
+            "_id": 1,
+            "homeAddress": { "zipCode": "1234", "tags": [{ "tagName": "a" }] },
+        });
+
+        assert_eq!(
+            transform(input, &options(Case::Camel)).unwrap(),
+            (expected, Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_transform_values() {
+        let input = json!({ "kind": "user_name", "count": 1 });
+        let expected = json!({ "kind": "userName", "count": 1 });
+
+        let mut options = options(Case::Camel);
+        options.values = true;
+
+        assert_eq!(transform(input, &options).unwrap().0, expected);
+    }
+
+    #[test]
+    fn test_include_and_exclude() {
+        let input = json!({
+            "first_key": { "inner_key": 1 },
+            "items": [{ "item_name": { "raw_key": 1 } }],
+        });
+
+        let expected = json!({
+            "first_key": { "inner_key": 1 },
+            "items": [{ "itemName": { "raw_key": 1 } }],
+        });
+
+        let mut options = options(Case::Camel);
+        options.include = vec![parse_pointer("/items").unwrap()];
+        options.exclude = vec![parse_pointer("/items/*/item_name/raw_key").unwrap()];
+
+        assert_eq!(transform(input, &options).unwrap().0, expected);
+    }
+
+    #[test]
+    fn test_collision() {
+        let input = json!({ "data": { "user_name": 1, "userName": 2 } });
+
+        let mut options = options(Case::Camel);
+        assert_eq!(
+            transform(input.clone(), &options).unwrap(),
+            (
+                json!({ "data": { "userName": 2 } }),
+                vec![Collision {
+                    path: "/data".to_string(),
+                    first: "user_name".to_string(),
+                    second: "userName".to_string(),
+                    key: "userName".to_string(),
+                }]
+            )
+        );
+
+        options.strict = true;
+        assert_eq!(
+            transform(input, &options).unwrap_err(),
+            "Key collision at /data: \"user_name\" and \"userName\" both become \"userName\""
+        );
+    }
+
+    #[test]
+    fn test_parse_pointer() {
+        assert_eq!(parse_pointer("").unwrap(), Vec::<String>::new());
+        assert_eq!(parse_pointer("/a~1b/c~0d").unwrap(), vec!["a/b", "c~d"]);
+        assert!(parse_pointer("a/b").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+mod toml;
+mod yaml;
+
+pub use self::toml::is_bare_key;
+
+use crate::custom::Target;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+/// Byte range of a key inside the document, without its quotes.
+#[derive(Debug)]
+pub struct Key {
+    pub start: usize,
+    pub end: usize,
+    pub quoted: bool,
+}
+
+pub fn find_keys(input: &str, format: Format) -> Result<Vec<Key>, String> {
+    match format {
+        // json is a subset of yaml flow collections.
+        Format::Json | Format::Yaml => yaml::find_keys(input),
+        Format::Toml => toml::find_keys(input),
+    }
+}
+
+/// Rewrites every key of the document, leaving everything else as it is.
+pub fn rewrite(input: &str, format: Format, to: &Target) -> Result<String, String> {
+    let mut result = String::with_capacity(input.len());
+    let mut last = 0;
+
+    for key in find_keys(input, format)? {
+        let text = &input[key.start..key.end];
+
+        let new_key = match to.convert_affixed(text) {
+            Some(new_key) => new_key,
+            None => continue,
+        };
+
+        result.push_str(&input[last..key.start]);
+
+        if format == Format::Toml && !key.quoted && !toml::is_bare_key(&new_key) {
+            result.push('"');
+            result.push_str(&new_key);
+            result.push('"');
+        } else {
+            result.push_str(&new_key);
+        }
+
+        last = key.end;
+    }
+
+    result.push_str(&input[last..]);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Case;
+
+    #[test]
+    fn test_rewrite_yaml() {
+        let input = "# keep_me\napi_version: v1\nmeta_data:\n  label_name: some_value # note_this\n  \"quoted_key\": 1\n";
+        let expected = "# keep_me\napiVersion: v1\nmetaData:\n  labelName: some_value # note_this\n  \"quotedKey\": 1\n";
+
+        assert_eq!(
+            rewrite(input, Format::Yaml, &Target::Case(Case::Camel)).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_rewrite_toml() {
+        let input = "[package_info]\n# keep_me\nfirst_name = \"john_doe\"\nlast_name='x'\n";
+        let expected = "[package-info]\n# keep_me\nfirst-name = \"john_doe\"\nlast-name='x'\n";
+
+        assert_eq!(
+            rewrite(input, Format::Toml, &Target::Case(Case::Kebab)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            rewrite("user_name = 1", Format::Toml, &Target::Case(Case::Title)).unwrap(),
+            "\"User Name\" = 1"
+        );
+    }
+}
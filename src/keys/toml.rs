@@ -0,0 +1,286 @@
+use super::Key;
+use crate::scanner::Scanner;
+
+/// Finds every key of a toml document: bare and quoted keys, each segment of
+/// dotted keys, table headers and the keys of inline tables.
+pub fn find_keys(input: &str) -> Result<Vec<Key>, String> {
+    let mut scanner = Scanner::new(input);
+    let mut keys = Vec::new();
+
+    loop {
+        scanner.skip_spaces();
+
+        match scanner.peek() {
+            None => break,
+            Some('\n') | Some('\r') => {
+                scanner.bump();
+            }
+            Some('#') => scanner.skip_comment(),
+            Some('[') => {
+                scanner.bump();
+                let closing = if scanner.eat('[') { "]]" } else { "]" };
+
+                parse_key(&mut scanner, &mut keys)?;
+
+                if !scanner.eat_str(closing) {
+                    return Err(scanner.error("expected end of table header"));
+                }
+
+                end_of_line(&mut scanner)?;
+            }
+            Some(_) => {
+                parse_key(&mut scanner, &mut keys)?;
+
+                if !scanner.eat('=') {
+                    return Err(scanner.error("expected `=` after key"));
+                }
+
+                parse_value(&mut scanner, &mut keys)?;
+                end_of_line(&mut scanner)?;
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+pub fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn end_of_line(scanner: &mut Scanner) -> Result<(), String> {
+    scanner.skip_spaces();
+
+    match scanner.peek() {
+        None | Some('\n') | Some('\r') => Ok(()),
+        Some('#') => {
+            scanner.skip_comment();
+            Ok(())
+        }
+        Some(_) => Err(scanner.error("expected end of line")),
+    }
+}
+
+/// Parses a possibly dotted key, leaving the scanner after its last segment.
+fn parse_key(scanner: &mut Scanner, keys: &mut Vec<Key>) -> Result<(), String> {
+    loop {
+        scanner.skip_spaces();
+
+        match scanner.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                scanner.bump();
+                let start = scanner.pos;
+                scanner.skip_quoted(quote)?;
+                keys.push(Key {
+                    start,
+                    end: scanner.pos - 1,
+                    quoted: true,
+                });
+            }
+            Some(_) => {
+                let start = scanner.pos;
+
+                while let Some(c) = scanner.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                        break;
+                    }
+                    scanner.bump();
+                }
+
+                if start == scanner.pos {
+                    return Err(scanner.error("expected key"));
+                }
+
+                keys.push(Key {
+                    start,
+                    end: scanner.pos,
+                    quoted: false,
+                });
+            }
+            None => return Err(scanner.error("expected key")),
+        }
+
+        scanner.skip_spaces();
+
+        if !scanner.eat('.') {
+            return Ok(());
+        }
+    }
+}
+
+fn parse_value(scanner: &mut Scanner, keys: &mut Vec<Key>) -> Result<(), String> {
+    scanner.skip_spaces();
+
+    match scanner.peek() {
+        Some(quote @ ('"' | '\'')) => {
+            let triple: String = [quote; 3].iter().collect();
+
+            if scanner.eat_str(&triple) {
+                return skip_multiline_string(scanner, &triple);
+            }
+
+            scanner.bump();
+            scanner.skip_quoted(quote)
+        }
+        Some('[') => {
+            scanner.bump();
+
+            loop {
+                skip_blank(scanner);
+
+                if scanner.eat(']') {
+                    return Ok(());
+                }
+
+                parse_value(scanner, keys)?;
+                skip_blank(scanner);
+
+                if !scanner.eat(',') && scanner.peek() != Some(']') {
+                    return Err(scanner.error("expected `,` or `]` in array"));
+                }
+            }
+        }
+        Some('{') => {
+            scanner.bump();
+            scanner.skip_spaces();
+
+            if scanner.eat('}') {
+                return Ok(());
+            }
+
+            loop {
+                parse_key(scanner, keys)?;
+
+                if !scanner.eat('=') {
+                    return Err(scanner.error("expected `=` after key"));
+                }
+
+                parse_value(scanner, keys)?;
+                scanner.skip_spaces();
+
+                if scanner.eat('}') {
+                    return Ok(());
+                }
+
+                if !scanner.eat(',') {
+                    return Err(scanner.error("expected `,` or `}` in inline table"));
+                }
+            }
+        }
+        Some(_) => {
+            let start = scanner.pos;
+
+            while let Some(c) = scanner.peek() {
+                if matches!(c, ',' | ']' | '}' | '#' | '\n' | '\r') {
+                    break;
+                }
+                scanner.bump();
+            }
+
+            if scanner.src[start..scanner.pos].trim().is_empty() {
+                return Err(scanner.error("expected value"));
+            }
+
+            Ok(())
+        }
+        None => Err(scanner.error("expected value")),
+    }
+}
+
+/// Skips whitespace, newlines and comments between array items.
+fn skip_blank(scanner: &mut Scanner) {
+    loop {
+        match scanner.peek() {
+            Some(' ' | '\t' | '\n' | '\r') => {
+                scanner.bump();
+            }
+            Some('#') => scanner.skip_comment(),
+            _ => return,
+        }
+    }
+}
+
+fn skip_multiline_string(scanner: &mut Scanner, delimiter: &str) -> Result<(), String> {
+    let quote = delimiter.chars().next().unwrap();
+
+    loop {
+        if scanner.eat_str(delimiter) {
+            // Up to two extra quotes may close the string, as in `""""""`.
+            scanner.eat(quote);
+            scanner.eat(quote);
+            return Ok(());
+        }
+
+        match scanner.bump() {
+            Some('\\') if quote == '"' => {
+                scanner.bump();
+            }
+            Some(_) => (),
+            None => return Err(scanner.error("unterminated multiline string")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_names(input: &str) -> Vec<&str> {
+        find_keys(input)
+            .unwrap()
+            .iter()
+            .map(|key| &input[key.start..key.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_find_keys() {
+        let input = r#"# top comment
+title = "my_title" # trailing
+[server.http_config]
+max_size = 10
+"quoted key" = 'a = b'
+nested.child_key = { inner_key = [1, 2], other = { deep_key = true } }
+list = [
+  "a", # comment
+  { item_key = 1 },
+]
+text = """
+fake_key = 1
+"""
+
+[[worker_pool]]
+pool_size = 2
+"#;
+
+        assert_eq!(
+            key_names(input),
+            vec![
+                "title",
+                "server",
+                "http_config",
+                "max_size",
+                "quoted key",
+                "nested",
+                "child_key",
+                "inner_key",
+                "other",
+                "deep_key",
+                "list",
+                "item_key",
+                "text",
+                "worker_pool",
+                "pool_size",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_toml() {
+        assert!(find_keys("key value").is_err());
+        assert!(find_keys("key = \"unterminated").is_err());
+    }
+}
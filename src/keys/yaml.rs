@@ -0,0 +1,345 @@
+use super::Key;
+use crate::scanner::Scanner;
+
+/// Finds the keys of every block and flow mapping of a yaml stream, skipping
+/// comments, block scalars, quoted and plain values, directives and merge keys.
+pub fn find_keys(input: &str) -> Result<Vec<Key>, String> {
+    let mut scanner = Scanner::new(input);
+    let mut keys = Vec::new();
+    let mut block_scalar_indent: Option<usize> = None;
+
+    while scanner.peek().is_some() {
+        let indent = scanner.skip_spaces();
+
+        if let Some(parent_indent) = block_scalar_indent {
+            if is_line_end(scanner.peek()) || indent > parent_indent {
+                scanner.skip_line();
+                continue;
+            }
+            block_scalar_indent = None;
+        }
+
+        if is_line_end(scanner.peek()) || scanner.peek() == Some('#') {
+            scanner.skip_line();
+            continue;
+        }
+
+        let rest = &input[scanner.pos..];
+
+        if indent == 0 && (rest.starts_with('%') || is_document_marker(rest)) {
+            scanner.skip_line();
+            continue;
+        }
+
+        while scanner.peek() == Some('-') && is_separator(scanner.peek_nth(1)) {
+            scanner.bump();
+            scanner.skip_spaces();
+        }
+
+        if let Some(key) = parse_block_key(&mut scanner)? {
+            if scanner.src[key.start..key.end] != *"<<" {
+                keys.push(key);
+            }
+        }
+
+        scanner.skip_spaces();
+        skip_properties(&mut scanner);
+
+        match scanner.peek() {
+            Some('|' | '>') => block_scalar_indent = Some(indent),
+            Some('{' | '[') => parse_flow(&mut scanner, &mut keys)?,
+            Some(quote @ ('"' | '\'')) => {
+                scanner.bump();
+                skip_quoted(&mut scanner, quote)?;
+            }
+            _ => (),
+        }
+
+        scanner.skip_line();
+    }
+
+    Ok(keys)
+}
+
+fn is_line_end(c: Option<char>) -> bool {
+    matches!(c, None | Some('\n') | Some('\r'))
+}
+
+fn is_separator(c: Option<char>) -> bool {
+    matches!(c, None | Some(' ' | '\t' | '\n' | '\r'))
+}
+
+fn is_document_marker(line: &str) -> bool {
+    (line.starts_with("---") || line.starts_with("...")) && is_separator(line[3..].chars().next())
+}
+
+/// Skips anchors and tags placed before a value, like `&base` or `!!str`.
+fn skip_properties(scanner: &mut Scanner) {
+    while matches!(scanner.peek(), Some('&' | '!')) {
+        while !is_separator(scanner.peek()) {
+            scanner.bump();
+        }
+        scanner.skip_spaces();
+    }
+}
+
+/// Parses `key:` at the scanner position. When the line doesn't start with a
+/// key the scanner is left untouched.
+fn parse_block_key(scanner: &mut Scanner) -> Result<Option<Key>, String> {
+    let start = scanner.pos;
+
+    match scanner.peek() {
+        Some(quote @ ('"' | '\'')) => {
+            scanner.bump();
+
+            if skip_quoted(scanner, quote).is_err() {
+                scanner.pos = start;
+                return Ok(None);
+            }
+
+            let end = scanner.pos;
+            scanner.skip_spaces();
+
+            if scanner.peek() == Some(':') && is_separator(scanner.peek_nth(1)) {
+                scanner.bump();
+                return Ok(Some(Key {
+                    start: start + 1,
+                    end: end - 1,
+                    quoted: true,
+                }));
+            }
+        }
+        Some(c) if !is_indicator(c) => {
+            while let Some(c) = scanner.peek() {
+                if is_line_end(Some(c)) || c == '#' && is_separator(scanner.peek_back()) {
+                    break;
+                }
+
+                if c == ':' && is_separator(scanner.peek_nth(1)) {
+                    let end = start + scanner.src[start..scanner.pos].trim_end().len();
+                    scanner.bump();
+                    return Ok(Some(Key {
+                        start,
+                        end,
+                        quoted: false,
+                    }));
+                }
+
+                scanner.bump();
+            }
+        }
+        _ => (),
+    }
+
+    scanner.pos = start;
+    Ok(None)
+}
+
+fn is_indicator(c: char) -> bool {
+    matches!(
+        c,
+        '[' | '{' | '|' | '>' | '&' | '*' | '!' | '%' | '@' | '`' | '#' | '?' | ','
+    )
+}
+
+/// Skips a quoted scalar whose opening quote was already consumed. Quoted
+/// scalars may span several lines.
+fn skip_quoted(scanner: &mut Scanner, quote: char) -> Result<(), String> {
+    loop {
+        match scanner.bump() {
+            Some('\\') if quote == '"' => {
+                scanner.bump();
+            }
+            Some('\'') if quote == '\'' && scanner.peek() == Some('\'') => {
+                scanner.bump();
+            }
+            Some(c) if c == quote => return Ok(()),
+            Some(_) => (),
+            None => return Err(scanner.error("unterminated quoted scalar")),
+        }
+    }
+}
+
+/// Parses a flow collection, which may span several lines, collecting the
+/// keys of its mappings.
+fn parse_flow(scanner: &mut Scanner, keys: &mut Vec<Key>) -> Result<(), String> {
+    let mut stack = Vec::new();
+    let mut expecting_key = false;
+
+    loop {
+        skip_flow_blank(scanner);
+
+        let c = match scanner.peek() {
+            Some(c) => c,
+            None => return Err(scanner.error("unterminated flow collection")),
+        };
+
+        match c {
+            '{' | '[' => {
+                scanner.bump();
+                stack.push(c);
+                expecting_key = c == '{';
+                continue;
+            }
+            '}' | ']' => {
+                scanner.bump();
+
+                if stack.pop() != Some(if c == '}' { '{' } else { '[' }) {
+                    return Err(scanner.error("mismatched flow collection"));
+                }
+
+                if stack.is_empty() {
+                    return Ok(());
+                }
+
+                expecting_key = false;
+                continue;
+            }
+            ',' => {
+                scanner.bump();
+                expecting_key = stack.last() == Some(&'{');
+                continue;
+            }
+            ':' => {
+                scanner.bump();
+                expecting_key = false;
+                continue;
+            }
+            _ => (),
+        }
+
+        skip_properties(scanner);
+
+        let start = scanner.pos;
+        let quoted = matches!(scanner.peek(), Some('"' | '\''));
+
+        if let Some(quote @ ('"' | '\'')) = scanner.peek() {
+            scanner.bump();
+            skip_quoted(scanner, quote)?;
+        } else {
+            while let Some(c) = scanner.peek() {
+                if matches!(c, ',' | '[' | ']' | '{' | '}' | '\n' | '\r')
+                    || c == ':' && is_flow_separator(scanner.peek_nth(1))
+                    || c == '#' && is_separator(scanner.peek_back())
+                {
+                    break;
+                }
+                scanner.bump();
+            }
+        }
+
+        let text = &scanner.src[start..scanner.pos];
+        let end = start + text.trim_end().len();
+
+        if expecting_key && end > start {
+            let next = scanner.src[scanner.pos..].trim_start_matches([' ', '\t']);
+
+            if next.starts_with(':') {
+                let (start, end) = if quoted {
+                    (start + 1, end - 1)
+                } else {
+                    (start, end)
+                };
+                keys.push(Key { start, end, quoted });
+            }
+        }
+
+        expecting_key = false;
+    }
+}
+
+fn is_flow_separator(c: Option<char>) -> bool {
+    is_separator(c) || matches!(c, Some(',' | '[' | ']' | '{' | '}'))
+}
+
+fn skip_flow_blank(scanner: &mut Scanner) {
+    loop {
+        match scanner.peek() {
+            Some(' ' | '\t' | '\n' | '\r') => {
+                scanner.bump();
+            }
+            Some('#') => scanner.skip_comment(),
+            _ => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_names(input: &str) -> Vec<&str> {
+        find_keys(input)
+            .unwrap()
+            .iter()
+            .map(|key| &input[key.start..key.end])
+            .collect()
+    }
+
+    #[test]
+    fn test_find_keys() {
+        let input = r#"%YAML 1.2
+---
+# comment: not_a_key
+api_version: v1 # trailing: comment
+meta_data:
+  "quoted_key": 'it''s'
+  label_map: {app_name: web, "tier_name": [a, b]}
+  script: |
+    fake_key: 1
+    other: 2
+  url: http://example.com:8080
+containers:
+  - image_name: nginx
+    port_list:
+      - container_port: 80
+  - - nested_key: 1
+base: &base
+  shared_key: 1
+derived:
+  <<: *base
+  note: >-
+    folded: text
+...
+"#;
+
+        assert_eq!(
+            key_names(input),
+            vec![
+                "api_version",
+                "meta_data",
+                "quoted_key",
+                "label_map",
+                "app_name",
+                "tier_name",
+                "script",
+                "url",
+                "containers",
+                "image_name",
+                "port_list",
+                "container_port",
+                "nested_key",
+                "base",
+                "shared_key",
+                "derived",
+                "note",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiline_flow() {
+        let input = "labels: {\n  first_key: 1, # comment\n  second_key: [x, y],\n}\nnext_key: 1\n";
+
+        assert_eq!(
+            key_names(input),
+            vec!["labels", "first_key", "second_key", "next_key"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        assert!(find_keys("key: {a: 1").is_err());
+        assert!(find_keys("key: \"unterminated").is_err());
+    }
+}
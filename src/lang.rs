@@ -0,0 +1,252 @@
+use clap::ValueEnum;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Language {
+    Rust,
+    Python,
+    #[value(alias = "js")]
+    Javascript,
+    #[value(alias = "ts")]
+    Typescript,
+    Go,
+    Java,
+    Sql,
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Language::Rust => write!(f, "rust"),
+            Language::Python => write!(f, "python"),
+            Language::Javascript => write!(f, "javascript"),
+            Language::Typescript => write!(f, "typescript"),
+            Language::Go => write!(f, "go"),
+            Language::Java => write!(f, "java"),
+            Language::Sql => write!(f, "sql"),
+        }
+    }
+}
+
+#[rustfmt::skip]
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+    "gen", "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+#[rustfmt::skip]
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+    "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "while", "with", "yield",
+];
+
+#[rustfmt::skip]
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+    "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for", "function",
+    "if", "implements", "import", "in", "instanceof", "interface", "let", "new", "null",
+    "package", "private", "protected", "public", "return", "static", "super", "switch", "this",
+    "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+/// Contextual typescript keywords that would still be confusing identifiers.
+#[rustfmt::skip]
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "abstract", "any", "as", "asserts", "declare", "infer", "is", "keyof", "module",
+    "namespace", "never", "readonly", "satisfies", "type", "unique", "unknown",
+];
+
+#[rustfmt::skip]
+const GO_KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough",
+    "for", "func", "go", "goto", "if", "import", "interface", "map", "package", "range",
+    "return", "select", "struct", "switch", "type", "var",
+];
+
+#[rustfmt::skip]
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "false", "final",
+    "finally", "float", "for", "goto", "if", "implements", "import", "instanceof", "int",
+    "interface", "long", "native", "new", "null", "package", "private", "protected", "public",
+    "return", "short", "static", "strictfp", "super", "switch", "synchronized", "this", "throw",
+    "throws", "transient", "true", "try", "void", "volatile", "while", "_",
+];
+
+/// Reserved words shared by the common sql dialects, matched ignoring case.
+#[rustfmt::skip]
+const SQL_KEYWORDS: &[&str] = &[
+    "add", "all", "alter", "and", "any", "as", "asc", "authorization", "begin", "between", "by",
+    "case", "cast", "check", "collate", "column", "commit", "constraint", "create", "cross",
+    "current_date", "current_time", "current_timestamp", "current_user", "database", "default",
+    "delete", "desc", "distinct", "drop", "else", "end", "except", "exists", "false", "fetch",
+    "for", "foreign", "from", "full", "grant", "group", "having", "if", "in", "index", "inner",
+    "insert", "intersect", "into", "is", "join", "key", "left", "like", "limit", "natural",
+    "not", "null", "offset", "on", "or", "order", "outer", "over", "partition", "primary",
+    "procedure", "references", "replace", "return", "returns", "revoke", "right", "rollback",
+    "row", "rows", "schema", "select", "session_user", "set", "some", "table", "then", "to",
+    "transaction", "trigger", "true", "union", "unique", "update", "user", "using", "values",
+    "view", "when", "where", "window", "with",
+];
+
+impl Language {
+    pub fn from_path(path: &Path) -> Option<Language> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Language::Rust),
+            "py" | "pyi" => Some(Language::Python),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Language::Javascript),
+            "ts" | "mts" | "cts" | "tsx" => Some(Language::Typescript),
+            "go" => Some(Language::Go),
+            "java" => Some(Language::Java),
+            "sql" => Some(Language::Sql),
+            _ => None,
+        }
+    }
+
+    pub fn keywords(&self) -> Vec<&'static str> {
+        match self {
+            Language::Rust => RUST_KEYWORDS.to_vec(),
+            Language::Python => PYTHON_KEYWORDS.to_vec(),
+            Language::Javascript => JAVASCRIPT_KEYWORDS.to_vec(),
+            Language::Typescript => [JAVASCRIPT_KEYWORDS, TYPESCRIPT_KEYWORDS].concat(),
+            Language::Go => GO_KEYWORDS.to_vec(),
+            Language::Java => JAVA_KEYWORDS.to_vec(),
+            Language::Sql => SQL_KEYWORDS.to_vec(),
+        }
+    }
+
+    pub fn is_keyword(&self, word: &str) -> bool {
+        match self {
+            Language::Sql => SQL_KEYWORDS.contains(&word.to_lowercase().as_str()),
+            Language::Typescript => {
+                JAVASCRIPT_KEYWORDS.contains(&word) || TYPESCRIPT_KEYWORDS.contains(&word)
+            }
+            _ => self.keywords().contains(&word),
+        }
+    }
+
+    /// How keywords are usually escaped in the language.
+    pub fn default_escape(&self) -> Escape {
+        match self {
+            Language::Rust => Escape::Raw,
+            Language::Sql => Escape::Quote,
+            _ => Escape::Suffix,
+        }
+    }
+
+    /// Makes a keyword usable as an identifier, or returns `None` when the
+    /// word is not a keyword.
+    pub fn escape(&self, word: &str, escape: &Escape) -> Option<String> {
+        if !self.is_keyword(word) {
+            return None;
+        }
+
+        let escaped = match escape {
+            // These keywords cannot be raw identifiers.
+            Escape::Raw if *self == Language::Rust && !RUST_NOT_RAW.contains(&word) => {
+                format!("r#{}", word)
+            }
+            Escape::Raw | Escape::Suffix => format!("{}_", word),
+            Escape::Quote => format!("\"{}\"", word),
+            Escape::Template(template) => template.replace("{}", word),
+        };
+
+        Some(escaped)
+    }
+}
+
+const RUST_NOT_RAW: &[&str] = &["crate", "self", "Self", "super"];
+
+/// How a keyword is turned into an identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Escape {
+    /// append an underscore: `type_`
+    Suffix,
+    /// use a raw identifier where the language has them: `r#type`
+    Raw,
+    /// quote the identifier: `"user"`
+    Quote,
+    /// replace `{}` in the template with the keyword
+    Template(String),
+}
+
+impl FromStr for Escape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "suffix" => Ok(Escape::Suffix),
+            "raw" => Ok(Escape::Raw),
+            "quote" => Ok(Escape::Quote),
+            _ if s.contains("{}") => Ok(Escape::Template(s.to_string())),
+            _ => Err(format!(
+                "Invalid escape \"{}\", use suffix, raw, quote or a template like \"k_{{}}\"",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_keyword() {
+        assert!(Language::Rust.is_keyword("type"));
+        assert!(Language::Rust.is_keyword("Self"));
+        assert!(!Language::Rust.is_keyword("Type"));
+        assert!(Language::Python.is_keyword("class"));
+        assert!(!Language::Python.is_keyword("type"));
+        assert!(Language::Typescript.is_keyword("type"));
+        assert!(!Language::Javascript.is_keyword("type"));
+        assert!(Language::Sql.is_keyword("SELECT"));
+        assert!(Language::Go.is_keyword("func"));
+    }
+
+    #[test]
+    fn test_escape() {
+        let escape = |lang: Language, word| lang.escape(word, &lang.default_escape());
+
+        assert_eq!(escape(Language::Rust, "type"), Some("r#type".to_string()));
+        assert_eq!(escape(Language::Rust, "self"), Some("self_".to_string()));
+        assert_eq!(escape(Language::Rust, "name"), None);
+        assert_eq!(
+            escape(Language::Python, "class"),
+            Some("class_".to_string())
+        );
+        assert_eq!(escape(Language::Java, "int"), Some("int_".to_string()));
+        assert_eq!(escape(Language::Sql, "user"), Some("\"user\"".to_string()));
+
+        let template = "k_{}".parse().unwrap();
+        assert_eq!(
+            Language::Rust.escape("match", &template),
+            Some("k_match".to_string())
+        );
+        assert_eq!(
+            Language::Python.escape("class", &Escape::Raw),
+            Some("class_".to_string())
+        );
+        assert!("prefix".parse::<Escape>().is_err());
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(
+            Language::from_path(Path::new("src/main.rs")),
+            Some(Language::Rust)
+        );
+        assert_eq!(
+            Language::from_path(Path::new("app.tsx")),
+            Some(Language::Typescript)
+        );
+        assert_eq!(Language::from_path(Path::new("README")), None);
+    }
+}
@@ -0,0 +1,616 @@
+use crate::lang::Language;
+use crate::scanner::Scanner;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenKind {
+    Identifier,
+    Keyword,
+    String,
+    Comment,
+}
+
+/// Byte range of a token. Strings and comments cover only their contents,
+/// without quotes, prefixes or comment markers.
+#[derive(Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn line_comment(lang: Language) -> &'static str {
+    match lang {
+        Language::Python => "#",
+        Language::Sql => "--",
+        _ => "//",
+    }
+}
+
+fn is_identifier_start(c: char, lang: Language) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$' && is_javascript(lang)
+}
+
+fn is_identifier_char(c: char, lang: Language) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' && is_javascript(lang)
+}
+
+fn is_javascript(lang: Language) -> bool {
+    matches!(lang, Language::Javascript | Language::Typescript)
+}
+
+/// Splits source code into identifiers, keywords, strings and comments,
+/// dropping everything else. Malformed input never fails: an unterminated
+/// string or comment simply runs to the end of the file.
+pub fn tokenize(src: &str, lang: Language) -> Vec<Token> {
+    let mut scanner = Scanner::new(src);
+    let mut tokens = Vec::new();
+    lex_code(&mut scanner, lang, &mut tokens, false);
+
+    tokens
+}
+
+/// Lexes code up to the end of the file or, inside an interpolation hole, up
+/// to the unmatched brace that closes it, or to a python conversion or format
+/// spec.
+fn lex_code(scanner: &mut Scanner, lang: Language, tokens: &mut Vec<Token>, hole: bool) {
+    let src = scanner.src;
+    let mut regex_allowed = true;
+    let mut depth = 0;
+
+    while let Some(c) = scanner.peek() {
+        let start = scanner.pos;
+        let rest = &src[start..];
+
+        if rest.starts_with(line_comment(lang)) {
+            scanner.pos += line_comment(lang).len();
+            scanner.skip_comment();
+            tokens.push(token(
+                TokenKind::Comment,
+                start + line_comment(lang).len(),
+                scanner.pos,
+            ));
+            continue;
+        }
+
+        if lang != Language::Python && rest.starts_with("/*") {
+            scanner.pos += 2;
+            let end = skip_block_comment(scanner, lang == Language::Rust);
+            tokens.push(token(TokenKind::Comment, start + 2, end));
+            continue;
+        }
+
+        if lex_template(scanner, lang, tokens) {
+            regex_allowed = false;
+            continue;
+        }
+
+        if let Some(string) = lex_string(scanner, lang) {
+            if lang == Language::Sql && c != '\'' {
+                tokens.push(token(TokenKind::Identifier, string.start, string.end));
+            } else {
+                tokens.push(string);
+            }
+            regex_allowed = false;
+            continue;
+        }
+
+        if c == '/' && is_javascript(lang) && regex_allowed {
+            skip_regex(scanner);
+            regex_allowed = false;
+            continue;
+        }
+
+        if is_identifier_start(c, lang) {
+            while scanner.peek().is_some_and(|c| is_identifier_char(c, lang)) {
+                scanner.bump();
+            }
+
+            let word = &src[start..scanner.pos];
+            let kind = if lang.is_keyword(word) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Identifier
+            };
+
+            regex_allowed = kind == TokenKind::Keyword;
+            tokens.push(token(kind, start, scanner.pos));
+            continue;
+        }
+
+        if hole && depth == 0 {
+            let python_end =
+                lang == Language::Python && (c == ':' || c == '!' && !rest.starts_with("!="));
+
+            if c == '}' || python_end {
+                return;
+            }
+        }
+
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = (depth - 1).max(0),
+            _ => (),
+        }
+
+        scanner.bump();
+
+        if c.is_alphanumeric() {
+            // Numbers with suffixes like `10u32` or `0xff`.
+            while scanner
+                .peek()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_')
+            {
+                scanner.bump();
+            }
+            regex_allowed = false;
+        } else if !c.is_whitespace() {
+            regex_allowed = !matches!(c, ')' | ']' | '}');
+        }
+    }
+}
+
+fn token(kind: TokenKind, start: usize, end: usize) -> Token {
+    Token { kind, start, end }
+}
+
+/// Skips a block comment whose opening marker was consumed, returning where
+/// its contents end.
+fn skip_block_comment(scanner: &mut Scanner, nested: bool) -> usize {
+    let mut depth = 1;
+
+    loop {
+        if scanner.eat_str("*/") {
+            depth -= 1;
+
+            if depth == 0 {
+                return scanner.pos - 2;
+            }
+        } else if nested && scanner.eat_str("/*") {
+            depth += 1;
+        } else if scanner.bump().is_none() {
+            return scanner.pos;
+        }
+    }
+}
+
+/// Lexes a string literal at the scanner position, including its prefixes,
+/// or leaves the scanner untouched when there is none.
+fn lex_string(scanner: &mut Scanner, lang: Language) -> Option<Token> {
+    let start = scanner.pos;
+    let rest = &scanner.src[start..];
+
+    match lang {
+        Language::Rust => {
+            let prefix_len = rest
+                .find(|c: char| !matches!(c, 'b' | 'c' | 'r'))
+                .unwrap_or(rest.len())
+                .min(2);
+            let prefix = &rest[..prefix_len];
+            let after = &rest[prefix_len..];
+
+            if prefix.contains('r') && (after.starts_with('"') || after.starts_with("#\"")) {
+                let hashes = after.len() - after.trim_start_matches('#').len();
+                scanner.pos += prefix_len + hashes + 1;
+                let closing = format!("\"{}", "#".repeat(hashes));
+                return Some(skip_until(scanner, &closing));
+            }
+
+            if !matches!(prefix, "" | "b" | "c") {
+                return None;
+            }
+
+            if after.starts_with('"') {
+                scanner.pos += prefix_len + 1;
+                return Some(skip_escaped(scanner, '"', true));
+            }
+
+            if after.starts_with('\'') && is_char_literal(&after[1..]) {
+                scanner.pos += prefix_len + 1;
+                return Some(skip_escaped(scanner, '\'', false));
+            }
+
+            None
+        }
+        Language::Python => {
+            let prefix_len = rest
+                .find(|c: char| !matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'u' | 'f'))
+                .unwrap_or(rest.len());
+
+            if prefix_len > 2 {
+                return None;
+            }
+
+            let after = &rest[prefix_len..];
+            let quote = after.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+
+            if after.starts_with(&quote.to_string().repeat(3)) {
+                scanner.pos += prefix_len + 3;
+                return Some(skip_until(scanner, &quote.to_string().repeat(3)));
+            }
+
+            scanner.pos += prefix_len + 1;
+            Some(skip_escaped(scanner, quote, false))
+        }
+        Language::Javascript | Language::Typescript | Language::Go => {
+            let quote = rest.chars().next()?;
+
+            match quote {
+                '"' | '\'' => {
+                    scanner.bump();
+                    Some(skip_escaped(scanner, quote, false))
+                }
+                '`' if lang == Language::Go => {
+                    scanner.bump();
+                    Some(skip_until(scanner, "`"))
+                }
+                '`' => {
+                    scanner.bump();
+                    Some(skip_escaped(scanner, quote, true))
+                }
+                _ => None,
+            }
+        }
+        Language::Java => {
+            let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+
+            if rest.starts_with("\"\"\"") {
+                scanner.pos += 3;
+                return Some(skip_until(scanner, "\"\"\""));
+            }
+
+            scanner.bump();
+            Some(skip_escaped(scanner, quote, false))
+        }
+        Language::Sql => {
+            let quote = rest
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '\'' | '"' | '`'))?;
+            scanner.bump();
+            Some(skip_doubled(scanner, quote))
+        }
+    }
+}
+
+/// Lexes a python f-string or a javascript template literal at the scanner
+/// position, pushing its text as strings and its interpolation holes as code,
+/// or leaves the scanner untouched when there is none.
+fn lex_template(scanner: &mut Scanner, lang: Language, tokens: &mut Vec<Token>) -> bool {
+    let rest = &scanner.src[scanner.pos..];
+
+    let (prefix_len, closing, raw) = match lang {
+        Language::Python => {
+            let prefix_len = rest
+                .find(|c: char| !matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'u' | 'f'))
+                .unwrap_or(rest.len());
+            let prefix = rest[..prefix_len].to_ascii_lowercase();
+
+            if prefix_len > 2 || !prefix.contains('f') {
+                return false;
+            }
+
+            let after = &rest[prefix_len..];
+            let quote = match after.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+                Some(quote) => quote.to_string(),
+                None => return false,
+            };
+
+            let closing = if after.starts_with(&quote.repeat(3)) {
+                quote.repeat(3)
+            } else {
+                quote
+            };
+
+            (prefix_len, closing, prefix.contains('r'))
+        }
+        Language::Javascript | Language::Typescript if rest.starts_with('`') => {
+            (0, "`".to_string(), false)
+        }
+        _ => return false,
+    };
+
+    let multiline = lang != Language::Python || closing.len() == 3;
+    let hole = if lang == Language::Python { "{" } else { "${" };
+    scanner.pos += prefix_len + closing.len();
+    let mut start = scanner.pos;
+
+    loop {
+        let end = scanner.pos;
+        let rest = &scanner.src[end..];
+
+        if rest.is_empty() || !multiline && rest.starts_with('\n') || scanner.eat_str(&closing) {
+            push_string(tokens, start, end);
+            return true;
+        }
+
+        if lang == Language::Python && (rest.starts_with("{{") || rest.starts_with("}}")) {
+            scanner.pos += 2;
+        } else if rest.starts_with(hole) {
+            push_string(tokens, start, end);
+            scanner.pos += hole.len();
+            lex_hole(scanner, lang, tokens);
+            start = scanner.pos;
+        } else if rest.starts_with("\\N{") && lang == Language::Python && !raw {
+            // Named unicode escapes like `\N{DASH}`.
+            while !matches!(scanner.bump(), None | Some('}')) {}
+        } else {
+            if rest.starts_with('\\') && !raw {
+                scanner.bump();
+            }
+            scanner.bump();
+        }
+    }
+}
+
+/// Lexes an interpolation hole whose opening brace was consumed, up to and
+/// including its closing brace.
+fn lex_hole(scanner: &mut Scanner, lang: Language, tokens: &mut Vec<Token>) {
+    lex_code(scanner, lang, tokens, true);
+
+    if lang == Language::Python {
+        // A conversion like `!r`, then a format spec with its own holes.
+        if scanner.eat('!') {
+            scanner.bump();
+        }
+
+        if scanner.eat(':') {
+            while let Some(c) = scanner.peek() {
+                if c == '}' {
+                    break;
+                }
+
+                scanner.bump();
+
+                if c == '{' {
+                    lex_hole(scanner, lang, tokens);
+                }
+            }
+        }
+    }
+
+    scanner.eat('}');
+}
+
+fn push_string(tokens: &mut Vec<Token>, start: usize, end: usize) {
+    if start < end {
+        tokens.push(token(TokenKind::String, start, end));
+    }
+}
+
+/// A quote starts a char literal rather than a lifetime like `'a`.
+fn is_char_literal(after_quote: &str) -> bool {
+    let mut chars = after_quote.chars();
+
+    match chars.next() {
+        Some('\\') => true,
+        Some(_) => chars.next() == Some('\''),
+        None => false,
+    }
+}
+
+fn skip_until(scanner: &mut Scanner, closing: &str) -> Token {
+    let start = scanner.pos;
+
+    loop {
+        let end = scanner.pos;
+
+        if scanner.eat_str(closing) {
+            return token(TokenKind::String, start, end);
+        }
+
+        if scanner.bump().is_none() {
+            return token(TokenKind::String, start, end);
+        }
+    }
+}
+
+fn skip_escaped(scanner: &mut Scanner, quote: char, multiline: bool) -> Token {
+    let start = scanner.pos;
+
+    loop {
+        let end = scanner.pos;
+
+        match scanner.peek() {
+            Some('\\') => {
+                scanner.bump();
+                scanner.bump();
+            }
+            Some('\n') if !multiline => return token(TokenKind::String, start, end),
+            Some(c) if c == quote => {
+                scanner.bump();
+                return token(TokenKind::String, start, end);
+            }
+            Some(_) => {
+                scanner.bump();
+            }
+            None => return token(TokenKind::String, start, end),
+        }
+    }
+}
+
+/// Skips an sql string or quoted identifier, where a doubled quote escapes it.
+fn skip_doubled(scanner: &mut Scanner, quote: char) -> Token {
+    let start = scanner.pos;
+
+    loop {
+        let end = scanner.pos;
+
+        match scanner.bump() {
+            Some(c) if c == quote => {
+                if !scanner.eat(quote) {
+                    return token(TokenKind::String, start, end);
+                }
+            }
+            Some(_) => (),
+            None => return token(TokenKind::String, start, end),
+        }
+    }
+}
+
+fn skip_regex(scanner: &mut Scanner) {
+    let mut in_class = false;
+    scanner.bump();
+
+    while let Some(c) = scanner.bump() {
+        match c {
+            '\\' => {
+                scanner.bump();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '/' if !in_class => break,
+            '\n' => return,
+            _ => (),
+        }
+    }
+
+    while scanner.peek().is_some_and(|c| c.is_alphabetic()) {
+        scanner.bump();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(src: &str, lang: Language) -> Vec<(TokenKind, &str)> {
+        tokenize(src, lang)
+            .iter()
+            .map(|t| (t.kind, &src[t.start..t.end]))
+            .collect()
+    }
+
+    use TokenKind::{Comment, Identifier, Keyword, String};
+
+    #[test]
+    fn test_rust() {
+        let src = "fn get_name<'a>(x: &'a str) -> char { /* a /* nested */ b */ r#\"raw\"# ; 'c' } // end";
+
+        assert_eq!(
+            tokens(src, Language::Rust),
+            vec![
+                (Keyword, "fn"),
+                (Identifier, "get_name"),
+                (Identifier, "a"),
+                (Identifier, "x"),
+                (Identifier, "a"),
+                (Identifier, "str"),
+                (Identifier, "char"),
+                (Comment, " a /* nested */ b "),
+                (String, "raw"),
+                (String, "c"),
+                (Comment, " end"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python() {
+        let src = "def get_name(self):\n    return f\"{x}\" + '''doc\nstring''' # note\n";
+
+        assert_eq!(
+            tokens(src, Language::Python),
+            vec![
+                (Keyword, "def"),
+                (Identifier, "get_name"),
+                (Identifier, "self"),
+                (Keyword, "return"),
+                (Identifier, "x"),
+                (String, "doc\nstring"),
+                (Comment, " note"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_javascript() {
+        let src = "const $userName = a / b + /[/]x/g.test(`tmpl`);";
+
+        assert_eq!(
+            tokens(src, Language::Javascript),
+            vec![
+                (Keyword, "const"),
+                (Identifier, "$userName"),
+                (Identifier, "a"),
+                (Identifier, "b"),
+                (Identifier, "test"),
+                (String, "tmpl"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let src = "f'a {x!r:>{width}} {{b}} {d[\"k\"]}' + rf\"\\{y}\"";
+
+        assert_eq!(
+            tokens(src, Language::Python),
+            vec![
+                (String, "a "),
+                (Identifier, "x"),
+                (Identifier, "width"),
+                (String, " {{b}} "),
+                (Identifier, "d"),
+                (String, "k"),
+                (String, "\\"),
+                (Identifier, "y"),
+            ]
+        );
+
+        let src = "`a ${f({ b: `${c}` })} \\${d}` / e";
+
+        assert_eq!(
+            tokens(src, Language::Javascript),
+            vec![
+                (String, "a "),
+                (Identifier, "f"),
+                (Identifier, "b"),
+                (Identifier, "c"),
+                (String, " \\${d}"),
+                (Identifier, "e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_java() {
+        let src = "String userName = \"\"\"\n  text \"block\"\n\"\"\"; char c = '\\'';";
+
+        assert_eq!(
+            tokens(src, Language::Java),
+            vec![
+                (Identifier, "String"),
+                (Identifier, "userName"),
+                (String, "\n  text \"block\"\n"),
+                (Keyword, "char"),
+                (Identifier, "c"),
+                (String, "\\'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_and_sql() {
+        assert_eq!(
+            tokens("func f() { s := `raw\\` }", Language::Go),
+            vec![
+                (Keyword, "func"),
+                (Identifier, "f"),
+                (Identifier, "s"),
+                (String, "raw\\"),
+            ]
+        );
+
+        assert_eq!(
+            tokens("SELECT \"userName\", 'it''s' FROM t -- c", Language::Sql),
+            vec![
+                (Keyword, "SELECT"),
+                (Identifier, "userName"),
+                (String, "it''s"),
+                (Keyword, "FROM"),
+                (Identifier, "t"),
+                (Comment, " c"),
+            ]
+        );
+    }
+}
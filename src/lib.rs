@@ -0,0 +1,983 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Detects and converts the case of words: `helloWorld`, `hello_world`,
+//! `Hello-World` and the like.
+
+extern crate alloc;
+
+pub mod casing;
+pub mod custom;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use casing::Casing;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Case {
+    Flat,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    AllCaps,
+    Kebab,
+    Train,
+    Spaced,
+    Title,
+    HttpHeader,
+    None,
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Case::Flat => write!(f, "flat"),
+            Case::Upper => write!(f, "upper"),
+            Case::Camel => write!(f, "camel"),
+            Case::Pascal => write!(f, "pascal"),
+            Case::Snake => write!(f, "snake"),
+            Case::AllCaps => write!(f, "all_caps"),
+            Case::Kebab => write!(f, "kebab"),
+            Case::Train => write!(f, "train"),
+            Case::Spaced => write!(f, "spaced"),
+            Case::Title => write!(f, "title"),
+            Case::HttpHeader => write!(f, "http_header"),
+            Case::None => write!(f, "none"),
+        }
+    }
+}
+
+impl PartialEq for Case {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Case::Flat, Case::Flat) => true,
+            (Case::Upper, Case::Upper) => true,
+            (Case::Camel, Case::Camel) => true,
+            (Case::Pascal, Case::Pascal) => true,
+            (Case::Snake, Case::Snake) => true,
+            (Case::AllCaps, Case::AllCaps) => true,
+            (Case::Kebab, Case::Kebab) => true,
+            (Case::Train, Case::Train) => true,
+            (Case::Spaced, Case::Spaced) => true,
+            (Case::Title, Case::Title) => true,
+            (Case::HttpHeader, Case::HttpHeader) => true,
+            (Case::None, Case::None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Case {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().replace('-', "_").as_str() {
+            "flat" => Ok(Case::Flat),
+            "upper" => Ok(Case::Upper),
+            "camel" => Ok(Case::Camel),
+            "pascal" => Ok(Case::Pascal),
+            "snake" => Ok(Case::Snake),
+            "all_caps" => Ok(Case::AllCaps),
+            "kebab" => Ok(Case::Kebab),
+            "train" => Ok(Case::Train),
+            "spaced" => Ok(Case::Spaced),
+            "title" => Ok(Case::Title),
+            "http_header" => Ok(Case::HttpHeader),
+            _ => Err(format!("Unknown case \"{}\"", name)),
+        }
+    }
+}
+
+fn is_first_upper(word: &str) -> bool {
+    let v: Vec<char> = word.chars().collect();
+    v[0].is_uppercase()
+}
+
+/// Detects the case of a word, `Case::None` when it has none.
+pub fn get_case(word: &str) -> Case {
+    let contains_dash = word.contains('-');
+    let contains_underscore = word.contains('_');
+    let contains_space = word.contains(' ');
+
+    if contains_dash && contains_underscore
+        || contains_dash && contains_space
+        || contains_underscore && contains_space
+    {
+        return Case::None;
+    }
+
+    let is_lowercased = word.to_lowercase() == word;
+
+    if !contains_dash && !contains_underscore {
+        if contains_space {
+            if is_lowercased {
+                return Case::Spaced;
+            } else if word.to_uppercase() == word {
+                return Case::Spaced;
+            } else if is_title_case(&word) {
+                return Case::Title;
+            }
+        } else {
+
+        if is_lowercased {
+            return Case::Flat;
+        } else if word.to_uppercase() == word {
+            return Case::Upper;
+        }
+
+        if is_first_upper(&word) {
+            return Case::Pascal;
+        } else {
+            return Case::Camel;
+        }
+        }
+
+    }
+
+    if contains_underscore {
+        if is_lowercased {
+            return Case::Snake;
+        } else if word.to_uppercase() == word {
+            return Case::AllCaps;
+        }
+    }
+
+    if contains_dash {
+        if is_lowercased {
+            return Case::Kebab;
+        } else if word.to_uppercase() == word {
+            return Case::Train;
+        } else if is_http_header_case(&word) {
+            return Case::HttpHeader;
+        }
+    }
+
+    return Case::None;
+}
+
+fn is_token_capitalized_case(word: &str, token: char) -> bool {
+    let mut first = true;
+    let mut found_token = false;
+
+    for c in word.chars() {
+        if first {
+            if !c.is_uppercase() || c == '-' || c == '_' || c == ' ' {
+                return false;
+            }
+            first = false;
+            continue;
+        }
+
+        if found_token {
+            if !c.is_uppercase() {
+                return false;
+            }
+            found_token = false;
+            continue;
+        }
+
+        if c == token {
+            found_token = true;
+            continue;
+        }
+
+        if c.is_uppercase() {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+fn is_http_header_case(word: &str) -> bool {
+    is_token_capitalized_case(word, '-')
+}
+
+fn is_title_case(word: &str) -> bool {
+    is_token_capitalized_case(word, ' ')
+}
+
+pub(crate) fn capitalize_first_letter(word: &str) -> String {
+    let mut v: Vec<char> = word.chars().collect();
+    if let Some(first) = v.first_mut() {
+        *first = first.to_uppercase().nth(0).unwrap();
+    }
+    return v.into_iter().collect();
+}
+
+fn lower_first_letter(word: &str) -> String {
+    let mut v: Vec<char> = word.chars().collect();
+    if let Some(first) = v.first_mut() {
+        *first = first.to_lowercase().nth(0).unwrap();
+    }
+    return v.into_iter().collect();
+}
+
+fn flat_word(word: &str) -> String {
+    word.replace("-", "").replace("_", "").replace(" ", "")
+}
+
+fn flat_case(word: &str) -> String {
+    flat_word(word).to_lowercase()
+}
+
+fn upper_case(word: &str) -> String {
+    flat_word(word).to_uppercase()
+}
+
+fn camel_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Camel => return word.to_string(),
+        Case::Pascal => return lower_first_letter(word),
+        Case::Flat => return word.to_string(),
+        Case::Upper => return word.to_string().to_lowercase(),
+        _ => (),
+    }
+
+    let mut result = String::new();
+    let mut first = true;
+
+    for part in word.split(|c| c == '-' || c == '_' || c == ' ') {
+        if part.is_empty() {
+            continue;
+        }
+
+        if first {
+            result.push_str(&part.to_lowercase());
+            first = false;
+        } else {
+            result.push_str(&capitalize_first_letter(&part.to_lowercase()));
+        }
+    }
+
+    lower_first_letter(&result)
+}
+
+fn pascal_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Camel => return capitalize_first_letter(word),
+        Case::Pascal => return word.to_string(),
+        Case::Flat => return capitalize_first_letter(&word),
+        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
+        _ => capitalize_first_letter(&camel_case(word, case)),
+    }
+}
+
+fn camel_or_pascal_to_token(word: &str, token: char) -> String {
+    let mut result = String::new();
+    let mut first = true;
+
+    for c in word.chars() {
+        if first {
+            result.push(c.to_lowercase().nth(0).unwrap());
+            first = false;
+            continue;
+        }
+
+        if c.is_uppercase() {
+            result.push(token);
+            result.push(c.to_lowercase().nth(0).unwrap());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn camel_or_pascal_to_upper_token(word: &str, token: char) -> String {
+    let mut result = String::new();
+    let mut first = true;
+
+    for c in word.chars() {
+        if first {
+            result.push(c.to_uppercase().nth(0).unwrap());
+            first = false;
+            continue;
+        }
+
+        if c.is_uppercase() {
+            result.push(token);
+        }
+
+        result.push(c);
+    }
+
+    result
+}
+
+fn snake_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Snake => return word.to_string(),
+        Case::AllCaps => return word.to_lowercase(),
+        Case::Flat => return word.to_string(),
+        Case::Upper => return word.to_lowercase(),
+        Case::Kebab => return word.replace("-", "_"),
+        Case::Train => return word.replace("-", "_").to_lowercase(),
+        Case::Spaced => return word.replace(" ", "_").to_lowercase(),
+        Case::Title => return word.replace(" ", "_").to_lowercase(),
+        Case::HttpHeader => return word.replace("-", "_").to_lowercase(),
+        _ => camel_or_pascal_to_token(word, '_'),
+    }
+}
+
+fn all_caps_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Snake => return word.to_uppercase(),
+        Case::AllCaps => return word.to_string(),
+        Case::Flat => return word.to_uppercase(),
+        Case::Upper => return word.to_string(),
+        Case::Kebab => return word.replace("-", "_").to_uppercase(),
+        Case::Train => return word.replace("-", "_"),
+        Case::Spaced => return word.replace(" ", "_").to_uppercase(),
+        Case::Title => return word.replace(" ", "_").to_uppercase(),
+        Case::HttpHeader => return word.replace("-", "_").to_uppercase(),
+        _ => camel_or_pascal_to_token(word, '_').to_uppercase(),
+    }
+}
+
+fn kebab_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Snake => return word.replace("_", "-"),
+        Case::AllCaps => return word.replace("_", "-").to_lowercase(),
+        Case::Flat => return word.to_string(),
+        Case::Upper => return word.to_lowercase(),
+        Case::Kebab => return word.to_string(),
+        Case::Train => return word.to_lowercase(),
+        Case::Spaced => return word.replace(" ", "-"),
+        Case::Title => return word.replace(" ", "-").to_lowercase(),
+        Case::HttpHeader => return word.to_lowercase(),
+        _ => camel_or_pascal_to_token(word, '-'),
+    }
+}
+
+fn train_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Snake => return word.replace("_", "-").to_uppercase(),
+        Case::AllCaps => return word.replace("_", "-"),
+        Case::Flat => return word.to_uppercase(),
+        Case::Upper => return word.to_string(),
+        Case::Kebab => return word.to_uppercase(),
+        Case::Train => return word.to_string(),
+        Case::Spaced => return word.replace(" ", "-").to_uppercase(),
+        Case::Title => return word.replace(" ", "-").to_uppercase(),
+        Case::HttpHeader => return word.replace("_", "-").to_uppercase(),
+        _ => camel_or_pascal_to_token(word, '-').to_uppercase(),
+    }
+}
+
+fn spaced_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Snake => return word.replace("_", " "),
+        Case::AllCaps => return word.replace("_", " ").to_lowercase(),
+        Case::Flat => return word.to_string(),
+        Case::Upper => return word.to_lowercase(),
+        Case::Kebab => return word.replace("-", " "),
+        Case::Train => return word.replace("-", " ").to_lowercase(),
+        Case::Spaced => return word.to_string(),
+        Case::Title => return word.to_lowercase(),
+        Case::HttpHeader => return word.replace("-", " ").to_lowercase(),
+        _ => camel_or_pascal_to_token(word, ' '),
+    }
+}
+
+fn token_to_token_capitalized_case(word: &str, token: char) -> String {
+    let mut result = String::new();
+    let mut first = true;
+
+    for part in word
+        .to_lowercase()
+        .split(|c| c == '-' || c == '_' || c == ' ')
+    {
+        if part.is_empty() {
+            continue;
+        }
+
+        if first {
+            first = false;
+        } else {
+            result.push(token);
+        }
+
+        result.push_str(&capitalize_first_letter(part));
+    }
+
+    result
+}
+
+fn title_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Flat => return capitalize_first_letter(&word),
+        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
+        Case::Camel => return camel_or_pascal_to_upper_token(word, ' '),
+        Case::Pascal => return camel_or_pascal_to_upper_token(word, ' '),
+        _ => token_to_token_capitalized_case(word, ' '),
+    }
+}
+
+fn http_header_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Flat => return capitalize_first_letter(&word),
+        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
+        Case::Camel => return camel_or_pascal_to_upper_token(word, '-'),
+        Case::Pascal => return camel_or_pascal_to_upper_token(word, '-'),
+        _ => token_to_token_capitalized_case(word, '-'),
+    }
+}
+
+/// Converts a word of the known `case` to `to`.
+pub fn convert(word: &str, case: Case, to: Case) -> String {
+    match to {
+        Case::Flat => flat_case(word),
+        Case::Upper => upper_case(word),
+        Case::Camel => camel_case(word, case),
+        Case::Pascal => pascal_case(word, case),
+        Case::Snake => snake_case(word, case),
+        Case::AllCaps => all_caps_case(word, case),
+        Case::Kebab => kebab_case(word, case),
+        Case::Train => train_case(word, case),
+        Case::Spaced => spaced_case(word, case),
+        Case::Title => title_case(word, case),
+        Case::HttpHeader => http_header_case(word, case),
+        Case::None => word.to_string(),
+    }
+}
+
+/// Converts a word keeping its leading and trailing separators, so keys like
+/// `_id` or `__typename` survive. Returns `None` when the case is unknown.
+pub fn convert_affixed(word: &str, to: Case) -> Option<String> {
+    let core = word.trim_matches(|c| c == '_' || c == '-');
+
+    if core.is_empty() {
+        return Some(word.to_string());
+    }
+
+    let case = get_case(core);
+
+    if case == Case::None {
+        return None;
+    }
+
+    let start = word.find(core).unwrap_or(0);
+    let prefix = &word[..start];
+    let suffix = &word[start + core.len()..];
+
+    Some(format!("{}{}{}", prefix, convert(core, case, to), suffix))
+}
+
+/// Splits any text into words, accepting mixed separators, humps and acronyms
+/// where `get_case` would give up: `E-mail Address` and `ZIPCode` both work.
+pub fn split_words(text: &str) -> Vec<String> {
+    words(text).map(String::from).collect()
+}
+
+/// The words of `split_words` as slices of the text, without allocating.
+pub fn words(text: &str) -> Words<'_> {
+    Words { rest: text }
+}
+
+pub struct Words<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let start = self.rest.find(char::is_alphanumeric)?;
+        let text = &self.rest[start..];
+        let mut chars = text.char_indices().peekable();
+        let mut previous: Option<char> = None;
+
+        while let Some((i, c)) = chars.next() {
+            if !c.is_alphanumeric() {
+                self.rest = &text[i..];
+                return Some(&text[..i]);
+            }
+
+            if let Some(previous) = previous {
+                let next_is_lower = chars.peek().is_some_and(|(_, n)| n.is_lowercase());
+
+                if c.is_uppercase() && (!previous.is_uppercase() || next_is_lower) {
+                    self.rest = &text[i..];
+                    return Some(&text[..i]);
+                }
+            }
+
+            previous = Some(c);
+        }
+
+        self.rest = "";
+        Some(text)
+    }
+}
+
+/// Joins words in the given case.
+pub fn join_words(words: &[String], to: Case) -> String {
+    let lower = |word: &String| word.to_lowercase();
+    let upper = |word: &String| word.to_uppercase();
+    let capital = |word: &String| capitalize_first_letter(&word.to_lowercase());
+
+    let join = |transform: &dyn Fn(&String) -> String, token: &str| -> String {
+        words
+            .iter()
+            .map(transform)
+            .collect::<Vec<String>>()
+            .join(token)
+    };
+
+    match to {
+        Case::Flat => join(&lower, ""),
+        Case::Upper => join(&upper, ""),
+        Case::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| if i == 0 { lower(word) } else { capital(word) })
+            .collect(),
+        Case::Pascal => join(&capital, ""),
+        Case::Snake => join(&lower, "_"),
+        Case::AllCaps => join(&upper, "_"),
+        Case::Kebab => join(&lower, "-"),
+        Case::Train => join(&upper, "-"),
+        Case::Spaced => join(&lower, " "),
+        Case::Title => join(&capital, " "),
+        Case::HttpHeader => join(&capital, "-"),
+        Case::None => join(&|word: &String| word.to_string(), " "),
+    }
+}
+
+/// Converts any text through `split_words`, so it never rejects its input.
+pub fn convert_lenient(text: &str, to: Case) -> String {
+    let mut result = String::with_capacity(text.len());
+    convert_into(text, to, &mut result).unwrap();
+    result
+}
+
+/// Writes the conversion of `convert_lenient` to `out`, with no allocation
+/// between the words of the text and the writer. Unlike `convert`, words are
+/// split by `split_words` rather than by the case of the text, so a run of
+/// capitals stays one word: `HTTPServer` becomes `http_server`, where
+/// `convert` from pascal case gives `h_t_t_p_server`.
+pub fn convert_into<W: fmt::Write>(text: &str, to: Case, out: &mut W) -> fmt::Result {
+    let (separator, first, rest) = match to {
+        Case::Flat => ("", Transform::Lower, Transform::Lower),
+        Case::Upper => ("", Transform::Upper, Transform::Upper),
+        Case::Camel => ("", Transform::Lower, Transform::Capital),
+        Case::Pascal => ("", Transform::Capital, Transform::Capital),
+        Case::Snake => ("_", Transform::Lower, Transform::Lower),
+        Case::AllCaps => ("_", Transform::Upper, Transform::Upper),
+        Case::Kebab => ("-", Transform::Lower, Transform::Lower),
+        Case::Train => ("-", Transform::Upper, Transform::Upper),
+        Case::Spaced => (" ", Transform::Lower, Transform::Lower),
+        Case::Title => (" ", Transform::Capital, Transform::Capital),
+        Case::HttpHeader => ("-", Transform::Capital, Transform::Capital),
+        Case::None => (" ", Transform::Keep, Transform::Keep),
+    };
+
+    for (i, word) in words(text).enumerate() {
+        if i > 0 {
+            out.write_str(separator)?;
+        }
+
+        match i {
+            0 => first.write(word, out)?,
+            _ => rest.write(word, out)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+enum Transform {
+    Lower,
+    Upper,
+    Capital,
+    Keep,
+}
+
+impl Transform {
+    fn write<W: fmt::Write>(self, word: &str, out: &mut W) -> fmt::Result {
+        match self {
+            Transform::Keep => out.write_str(word),
+            Transform::Lower => {
+                write_mapped(word, out, char::is_ascii_lowercase, char::to_lowercase)
+            }
+            Transform::Upper => {
+                write_mapped(word, out, char::is_ascii_uppercase, char::to_uppercase)
+            }
+            Transform::Capital => {
+                let mut chars = word.chars();
+                let mut first = chars.next().into_iter().flat_map(char::to_lowercase);
+
+                if let Some(c) = first.next().and_then(|c| c.to_uppercase().next()) {
+                    out.write_char(c)?;
+                }
+
+                first.try_for_each(|c| out.write_char(c))?;
+                write_mapped(
+                    chars.as_str(),
+                    out,
+                    char::is_ascii_lowercase,
+                    char::to_lowercase,
+                )
+            }
+        }
+    }
+}
+
+/// Writes `text` through `map`, copying the runs of digits and of characters
+/// `map` would keep as they are.
+fn write_mapped<W, I>(
+    text: &str,
+    out: &mut W,
+    keeps: fn(&char) -> bool,
+    map: fn(char) -> I,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    I: Iterator<Item = char>,
+{
+    let mut start = 0;
+
+    for (i, c) in text.char_indices() {
+        if keeps(&c) || c.is_ascii_digit() {
+            continue;
+        }
+
+        out.write_str(&text[start..i])?;
+        map(c).try_for_each(|c| out.write_char(c))?;
+        start = i + c.len_utf8();
+    }
+
+    out.write_str(&text[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    static FLAT: &str = "helloworld";
+    static UPPER: &str = "HELLOWORLD";
+    static CAMEL: &str = "helloWorld";
+    static BROKEN_PASCAL: &str = "Helloworld";
+    static PASCAL: &str = "HelloWorld";
+    static SNAKE: &str = "hello_world";
+    static ALL_CAPS: &str = "HELLO_WORLD";
+    static KEBAB: &str = "hello-world";
+    static TRAIN: &str = "HELLO-WORLD";
+    static SPACED: &str = "hello world";
+    static TITLE: &str = "Hello World";
+    static HTTP_HEADER: &str = "Hello-World";
+
+    #[test]
+    fn test_flat_case() {
+        assert_eq!(flat_case(FLAT), FLAT);
+        assert_eq!(flat_case(UPPER), FLAT);
+        assert_eq!(flat_case(CAMEL), FLAT);
+        assert_eq!(flat_case(PASCAL), FLAT);
+        assert_eq!(flat_case(SNAKE), FLAT);
+        assert_eq!(flat_case(ALL_CAPS), FLAT);
+        assert_eq!(flat_case(KEBAB), FLAT);
+        assert_eq!(flat_case(TRAIN), FLAT);
+        assert_eq!(flat_case(SPACED), FLAT);
+        assert_eq!(flat_case(TITLE), FLAT);
+        assert_eq!(flat_case(HTTP_HEADER), FLAT);
+    }
+
+    #[test]
+    fn test_upper_case() {
+        assert_eq!(upper_case(FLAT), UPPER);
+        assert_eq!(upper_case(UPPER), UPPER);
+        assert_eq!(upper_case(CAMEL), UPPER);
+        assert_eq!(upper_case(PASCAL), UPPER);
+        assert_eq!(upper_case(SNAKE), UPPER);
+        assert_eq!(upper_case(ALL_CAPS), UPPER);
+        assert_eq!(upper_case(KEBAB), UPPER);
+        assert_eq!(upper_case(TRAIN), UPPER);
+        assert_eq!(upper_case(SPACED), UPPER);
+        assert_eq!(upper_case(TITLE), UPPER);
+        assert_eq!(upper_case(HTTP_HEADER), UPPER);
+    }
+
+    #[test]
+    fn test_camel_case() {
+        fn camel_case_helper(word: &str) -> String {
+            camel_case(word, get_case(word))
+        }
+
+        assert_eq!(camel_case_helper(FLAT), FLAT);
+        assert_eq!(camel_case_helper(UPPER), FLAT);
+        assert_eq!(camel_case_helper(CAMEL), CAMEL);
+        assert_eq!(camel_case_helper(PASCAL), CAMEL);
+        assert_eq!(camel_case_helper(SNAKE), CAMEL);
+        assert_eq!(camel_case_helper(ALL_CAPS), CAMEL);
+        assert_eq!(camel_case_helper(KEBAB), CAMEL);
+        assert_eq!(camel_case_helper(TRAIN), CAMEL);
+        assert_eq!(camel_case_helper(SPACED), CAMEL);
+        assert_eq!(camel_case_helper(TITLE), CAMEL);
+        assert_eq!(camel_case_helper(HTTP_HEADER), CAMEL);
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        fn pascal_case_helper(word: &str) -> String {
+            pascal_case(word, get_case(word))
+        }
+
+        assert_eq!(pascal_case_helper(FLAT), BROKEN_PASCAL);
+        assert_eq!(pascal_case_helper(UPPER), BROKEN_PASCAL);
+        assert_eq!(pascal_case_helper(CAMEL), PASCAL);
+        assert_eq!(pascal_case_helper(PASCAL), PASCAL);
+        assert_eq!(pascal_case_helper(SNAKE), PASCAL);
+        assert_eq!(pascal_case_helper(ALL_CAPS), PASCAL);
+        assert_eq!(pascal_case_helper(KEBAB), PASCAL);
+        assert_eq!(pascal_case_helper(TRAIN), PASCAL);
+        assert_eq!(pascal_case_helper(SPACED), PASCAL);
+        assert_eq!(pascal_case_helper(TITLE), PASCAL);
+        assert_eq!(pascal_case_helper(HTTP_HEADER), PASCAL);
+    }
+
+    #[test]
+    fn test_snake_case() {
+        fn snake_case_helper(word: &str) -> String {
+            snake_case(word, get_case(word))
+        }
+
+        assert_eq!(snake_case_helper(FLAT), FLAT);
+        assert_eq!(snake_case_helper(UPPER), FLAT);
+        assert_eq!(snake_case_helper(CAMEL), SNAKE);
+        assert_eq!(snake_case_helper(PASCAL), SNAKE);
+        assert_eq!(snake_case_helper(SNAKE), SNAKE);
+        assert_eq!(snake_case_helper(ALL_CAPS), SNAKE);
+        assert_eq!(snake_case_helper(KEBAB), SNAKE);
+        assert_eq!(snake_case_helper(TRAIN), SNAKE);
+        assert_eq!(snake_case_helper(SPACED), SNAKE);
+        assert_eq!(snake_case_helper(TITLE), SNAKE);
+        assert_eq!(snake_case_helper(HTTP_HEADER), SNAKE);
+    }
+
+    #[test]
+    fn test_all_caps_case() {
+        fn all_caps_case_helper(word: &str) -> String {
+            all_caps_case(word, get_case(word))
+        }
+
+        assert_eq!(all_caps_case_helper(FLAT), UPPER);
+        assert_eq!(all_caps_case_helper(UPPER), UPPER);
+        assert_eq!(all_caps_case_helper(CAMEL), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(PASCAL), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(SNAKE), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(ALL_CAPS), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(KEBAB), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(TRAIN), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(SPACED), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(TITLE), ALL_CAPS);
+        assert_eq!(all_caps_case_helper(HTTP_HEADER), ALL_CAPS);
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        fn kebab_case_helper(word: &str) -> String {
+            kebab_case(word, get_case(word))
+        }
+
+        assert_eq!(kebab_case_helper(FLAT), FLAT);
+        assert_eq!(kebab_case_helper(UPPER), FLAT);
+        assert_eq!(kebab_case_helper(CAMEL), KEBAB);
+        assert_eq!(kebab_case_helper(PASCAL), KEBAB);
+        assert_eq!(kebab_case_helper(SNAKE), KEBAB);
+        assert_eq!(kebab_case_helper(ALL_CAPS), KEBAB);
+        assert_eq!(kebab_case_helper(KEBAB), KEBAB);
+        assert_eq!(kebab_case_helper(TRAIN), KEBAB);
+        assert_eq!(kebab_case_helper(SPACED), KEBAB);
+        assert_eq!(kebab_case_helper(TITLE), KEBAB);
+        assert_eq!(kebab_case_helper(HTTP_HEADER), KEBAB);
+    }
+
+    #[test]
+    fn test_train_case() {
+        fn train_case_helper(word: &str) -> String {
+            train_case(word, get_case(word))
+        }
+
+        assert_eq!(train_case_helper(FLAT), UPPER);
+        assert_eq!(train_case_helper(UPPER), UPPER);
+        assert_eq!(train_case_helper(CAMEL), TRAIN);
+        assert_eq!(train_case_helper(PASCAL), TRAIN);
+        assert_eq!(train_case_helper(SNAKE), TRAIN);
+        assert_eq!(train_case_helper(ALL_CAPS), TRAIN);
+        assert_eq!(train_case_helper(KEBAB), TRAIN);
+        assert_eq!(train_case_helper(TRAIN), TRAIN);
+        assert_eq!(train_case_helper(SPACED), TRAIN);
+        assert_eq!(train_case_helper(TITLE), TRAIN);
+        assert_eq!(train_case_helper(HTTP_HEADER), TRAIN);
+    }
+
+    #[test]
+    fn test_spaced_case() {
+        fn spaced_case_helper(word: &str) -> String {
+            spaced_case(word, get_case(word))
+        }
+
+        assert_eq!(spaced_case_helper(FLAT), FLAT);
+        assert_eq!(spaced_case_helper(UPPER), FLAT);
+        assert_eq!(spaced_case_helper(CAMEL), SPACED);
+        assert_eq!(spaced_case_helper(PASCAL), SPACED);
+        assert_eq!(spaced_case_helper(SNAKE), SPACED);
+        assert_eq!(spaced_case_helper(ALL_CAPS), SPACED);
+        assert_eq!(spaced_case_helper(KEBAB), SPACED);
+        assert_eq!(spaced_case_helper(TRAIN), SPACED);
+        assert_eq!(spaced_case_helper(SPACED), SPACED);
+        assert_eq!(spaced_case_helper(TITLE), SPACED);
+        assert_eq!(spaced_case_helper(HTTP_HEADER), SPACED);
+    }
+
+    #[test]
+    fn test_title_case() {
+        fn title_case_helper(word: &str) -> String {
+            title_case(word, get_case(word))
+        }
+
+        assert_eq!(title_case_helper(FLAT), BROKEN_PASCAL);
+        assert_eq!(title_case_helper(UPPER), BROKEN_PASCAL);
+        assert_eq!(title_case_helper(CAMEL), TITLE);
+        assert_eq!(title_case_helper(PASCAL), TITLE);
+        assert_eq!(title_case_helper(SNAKE), TITLE);
+        assert_eq!(title_case_helper(ALL_CAPS), TITLE);
+        assert_eq!(title_case_helper(KEBAB), TITLE);
+        assert_eq!(title_case_helper(TRAIN), TITLE);
+        assert_eq!(title_case_helper(SPACED), TITLE);
+        assert_eq!(title_case_helper(TITLE), TITLE);
+        assert_eq!(title_case_helper(HTTP_HEADER), TITLE);
+    }
+
+    #[test]
+    fn test_http_header_case() {
+        fn http_header_case_helper(word: &str) -> String {
+            http_header_case(word, get_case(word))
+        }
+
+        assert_eq!(http_header_case_helper(FLAT), BROKEN_PASCAL);
+        assert_eq!(http_header_case_helper(UPPER), BROKEN_PASCAL);
+        assert_eq!(http_header_case_helper(CAMEL), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(PASCAL), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(SNAKE), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(ALL_CAPS), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(KEBAB), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(TRAIN), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(SPACED), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(TITLE), HTTP_HEADER);
+        assert_eq!(http_header_case_helper(HTTP_HEADER), HTTP_HEADER);
+    }
+
+    #[test]
+    fn test_get_case() {
+        assert_eq!(get_case(FLAT), Case::Flat);
+        assert_eq!(get_case(UPPER), Case::Upper);
+        assert_eq!(get_case(CAMEL), Case::Camel);
+        assert_eq!(get_case(PASCAL), Case::Pascal);
+        assert_eq!(get_case(SNAKE), Case::Snake);
+        assert_eq!(get_case(ALL_CAPS), Case::AllCaps);
+        assert_eq!(get_case(KEBAB), Case::Kebab);
+        assert_eq!(get_case(TRAIN), Case::Train);
+        assert_eq!(get_case(SPACED), Case::Spaced);
+        assert_eq!(get_case(TITLE), Case::Title);
+        assert_eq!(get_case(HTTP_HEADER), Case::HttpHeader);
+
+        assert_eq!(get_case("hello-new_world"), Case::None);
+        assert_eq!(get_case("hello-World"), Case::None);
+        assert_eq!(get_case("hello new-world"), Case::None);
+        assert_eq!(get_case("hello_new-world of programming"), Case::None);
+        assert_eq!(get_case("Broken-HttP-Header"), Case::None);
+        assert_eq!(get_case("Broken space case"), Case::None);
+    }
+
+    #[test]
+    fn test_case_from_str() {
+        assert_eq!("kebab".parse::<Case>(), Ok(Case::Kebab));
+        assert_eq!("all-caps".parse::<Case>(), Ok(Case::AllCaps));
+        assert_eq!("HTTP_HEADER".parse::<Case>(), Ok(Case::HttpHeader));
+        assert!("none".parse::<Case>().is_err());
+        assert!("unknown".parse::<Case>().is_err());
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("E-mail Address"), vec!["E", "mail", "Address"]);
+        assert_eq!(split_words("ZIP code"), vec!["ZIP", "code"]);
+        assert_eq!(
+            split_words("parseHTTPResponse2"),
+            vec!["parse", "HTTP", "Response2"]
+        );
+        assert_eq!(
+            split_words("  hello__new-World "),
+            vec!["hello", "new", "World"]
+        );
+        assert_eq!(split_words("--"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_convert_lenient() {
+        assert_eq!(convert_lenient("First Name", Case::Snake), "first_name");
+        assert_eq!(
+            convert_lenient("hello-new_world", Case::Camel),
+            "helloNewWorld"
+        );
+        assert_eq!(convert_lenient("HTTPServer", Case::Kebab), "http-server");
+        assert_eq!(
+            convert_lenient("hello world", Case::HttpHeader),
+            HTTP_HEADER
+        );
+        assert_eq!(convert_lenient("", Case::Camel), "");
+    }
+
+    #[test]
+    fn test_words() {
+        let text = "parseHTTPResponse2 for the ZIP-code";
+        let slices: Vec<&str> = words(text).collect();
+
+        assert_eq!(
+            slices,
+            vec!["parse", "HTTP", "Response2", "for", "the", "ZIP", "code"]
+        );
+        assert_eq!(words("  __ ").next(), None);
+        assert_eq!(words("Größe Maß").collect::<Vec<_>>(), vec!["Größe", "Maß"]);
+    }
+
+    #[test]
+    fn test_convert_into() {
+        let mut out = String::from("let ");
+
+        convert_into("HTTPServer", Case::Snake, &mut out).unwrap();
+        out.push_str(" = ");
+        convert_into("max-size", Case::Title, &mut out).unwrap();
+
+        assert_eq!(out, "let http_server = Max Size");
+
+        // the same as convert for the words it splits the same way
+        for (word, case) in [("helloWorld", Case::Camel), ("HELLO-WORLD", Case::Train)] {
+            for to in [Case::Snake, Case::Pascal, Case::HttpHeader] {
+                let mut out = String::new();
+                convert_into(word, to, &mut out).unwrap();
+                assert_eq!(out, convert(word, case, to));
+            }
+        }
+
+        let mut out = String::new();
+        convert_into("HTTPServer", Case::Snake, &mut out).unwrap();
+        assert_eq!(
+            convert("HTTPServer", Case::Pascal, Case::Snake),
+            "h_t_t_p_server"
+        );
+        assert_eq!(out, "http_server");
+    }
+}
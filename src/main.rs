@@ -1,666 +1,681 @@
+mod abbrev;
 mod args;
-use args::Args;
-use clap::Parser;
-use std::{fmt, process};
-
-#[derive(Debug)]
-enum Case {
-    Flat,
-    Upper,
-    Camel,
-    Pascal,
-    Snake,
-    AllCaps,
-    Kebab,
-    Train,
-    Spaced,
-    Title,
-    HttpHeader,
-    None,
+mod ascii;
+mod check;
+mod config;
+mod css;
+mod env;
+mod files;
+mod json;
+mod keys;
+mod lang;
+mod lexer;
+mod preset;
+mod rename;
+mod sanitize;
+mod scanner;
+mod segment;
+mod slug;
+mod table;
+use args::{
+    Args, CaseArgs, CheckArgs, Command, CompletionsArgs, ConfigCommand, ConvertArgs, CsvArgs,
+    DetectArgs, EnvArgs, FilesArgs, JsonArgs, KeysArgs, ManArgs, RenameArgs,
+};
+use clap::CommandFactory;
+use lang::{Escape, Language};
+use preset::Preset;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{fs, process};
+use wcase::custom::{self, CustomCase, Target};
+use wcase::{convert, convert_affixed, convert_lenient, get_case, join_words, split_words, Case};
+
+fn stderr(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    process::exit(1);
 }
 
-impl fmt::Display for Case {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Case::Flat => write!(f, "flat"),
-            Case::Upper => write!(f, "upper"),
-            Case::Camel => write!(f, "camel"),
-            Case::Pascal => write!(f, "pascal"),
-            Case::Snake => write!(f, "snake"),
-            Case::AllCaps => write!(f, "all_caps"),
-            Case::Kebab => write!(f, "kebab"),
-            Case::Train => write!(f, "train"),
-            Case::Spaced => write!(f, "spaced"),
-            Case::Title => write!(f, "title"),
-            Case::HttpHeader => write!(f, "http_header"),
-            Case::None => write!(f, "none"),
-        }
-    }
+fn stdout(msg: &str) -> ! {
+    println!("{}", msg);
+    process::exit(0);
 }
 
-impl PartialEq for Case {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Case::Flat, Case::Flat) => true,
-            (Case::Upper, Case::Upper) => true,
-            (Case::Camel, Case::Camel) => true,
-            (Case::Pascal, Case::Pascal) => true,
-            (Case::Snake, Case::Snake) => true,
-            (Case::AllCaps, Case::AllCaps) => true,
-            (Case::Kebab, Case::Kebab) => true,
-            (Case::Train, Case::Train) => true,
-            (Case::Spaced, Case::Spaced) => true,
-            (Case::Title, Case::Title) => true,
-            (Case::HttpHeader, Case::HttpHeader) => true,
-            (Case::None, Case::None) => true,
-            _ => false,
-        }
+fn file_name(file: &Option<PathBuf>) -> String {
+    match file {
+        Some(path) => path.display().to_string(),
+        None => "<stdin>".to_string(),
     }
 }
 
-fn stderr(msg: &str) {
-    eprintln!("{}", msg);
-    process::exit(1);
-}
-
-fn stdout(msg: &str) {
-    println!("{}", msg);
-    process::exit(0);
+fn read_input(file: &Option<PathBuf>) -> Result<String, String> {
+    match file {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e)),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Could not read stdin: {}", e))?;
+            Ok(input)
+        }
+    }
 }
 
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse_checked();
 
-    let case = get_case(&args.word);
+    let config = match args.no_config {
+        true => config::Config::default(),
+        false => config::Config::load().unwrap_or_else(|e| stderr(&e)),
+    };
 
-    if case == Case::None {
-        stderr("Invalid input");
+    let mut command = args.take_command();
+    args.merge(&config).unwrap_or_else(|e| stderr(&e));
+    command
+        .merge(&config, &args.define)
+        .unwrap_or_else(|e| stderr(&e));
+
+    match command {
+        Command::Convert(convert_args) if convert_args.slug => slug_command(convert_args),
+        Command::Convert(convert_args) => word_command(convert_args, &args.define),
+        Command::Detect(detect_args) => detect_command(detect_args, &args.define),
+        Command::Rename(rename_args) => rename_command(rename_args, &args.define),
+        Command::Json(json_args) => json_command(json_args, &args.define),
+        Command::Yaml(keys_args) => keys_command(keys_args, keys::Format::Yaml, &args.define),
+        Command::Toml(keys_args) => keys_command(keys_args, keys::Format::Toml, &args.define),
+        Command::Csv(csv_args) => csv_command(csv_args, &args.define),
+        Command::Env(env_args) => env_command(env_args, &args.define),
+        Command::Check(check_args) => check_command(check_args, &args.define),
+        Command::Files(files_args) => files_command(files_args, &args.define),
+        Command::Config(ConfigCommand::Show) => config_show_command(&config),
+        Command::Completions(completions_args) => completions_command(completions_args),
+        Command::Man(man_args) => man_command(man_args),
     }
+}
 
-    let result = match args {
-        Args { flat: true, .. } => flat_case(&args.word),
-        Args { upper: true, .. } => upper_case(&args.word),
-        Args { camel: true, .. } => camel_case(&args.word, case),
-        Args { pascal: true, .. } => pascal_case(&args.word, case),
-        Args { snake: true, .. } => snake_case(&args.word, case),
-        Args { all_caps: true, .. } => all_caps_case(&args.word, case),
-        Args { kebab: true, .. } => kebab_case(&args.word, case),
-        Args { train: true, .. } => train_case(&args.word, case),
-        Args { spaced: true, .. } => spaced_case(&args.word, case),
-        Args { title: true, .. } => title_case(&args.word, case),
-        Args {
-            http_header: true, ..
-        } => http_header_case(&args.word, case),
-        _ => case.to_string(),
-    };
+fn config_show_command(config: &config::Config) {
+    match config.show() {
+        show if show.is_empty() => stdout("No configuration file found"),
+        show => {
+            print!("{}", show);
+            process::exit(0);
+        }
+    }
+}
 
-    stdout(&result);
+fn completions_command(args: CompletionsArgs) {
+    clap_complete::generate(args.shell, &mut Args::command(), "wcase", &mut io::stdout());
 }
 
-fn is_first_upper(word: &str) -> bool {
-    let v: Vec<char> = word.chars().collect();
-    v[0].is_uppercase()
+fn man_command(args: ManArgs) {
+    let result = match &args.dir {
+        Some(dir) => clap_mangen::generate_to(Args::command(), dir)
+            .map_err(|e| format!("Could not write to {}: {}", dir.display(), e)),
+        None => clap_mangen::Man::new(Args::command())
+            .render(&mut io::stdout())
+            .map_err(|e| format!("Could not write the man page: {}", e)),
+    };
+
+    result.unwrap_or_else(|e| stderr(&e));
 }
 
-fn get_case(word: &str) -> Case {
-    let contains_dash = word.contains('-');
-    let contains_underscore = word.contains('_');
-    let contains_space = word.contains(' ');
+fn word_command(args: ConvertArgs, custom: &[CustomCase]) {
+    let lang = args
+        .lang
+        .or(args.preset.and_then(|preset| preset.language()));
 
-    if contains_dash && contains_underscore
-        || contains_dash && contains_space
-        || contains_underscore && contains_space
-    {
-        return Case::None;
+    if args.escape.is_some() && lang.is_none() {
+        stderr("Use --lang or a language preset to escape keywords");
     }
 
-    let is_lowercased = word.to_lowercase() == word;
-
-    if !contains_dash && !contains_underscore {
-        if contains_space {
-            if is_lowercased {
-                return Case::Spaced;
-            } else if word.to_uppercase() == word {
-                return Case::Spaced;
-            } else if is_title_case(&word) {
-                return Case::Title;
-            }
-        } else {
+    let shortener = args.max_len.map(|max_len| {
+        let mut custom = Vec::new();
 
-        if is_lowercased {
-            return Case::Flat;
-        } else if word.to_uppercase() == word {
-            return Case::Upper;
+        if let Some(file) = &args.abbrev_file {
+            let text = read_input(&Some(file.clone())).unwrap_or_else(|e| stderr(&e));
+            custom = abbrev::parse_dictionary(&text).unwrap_or_else(|e| stderr(&e));
         }
 
-        if is_first_upper(&word) {
-            return Case::Pascal;
-        } else {
-            return Case::Camel;
-        }
+        for entry in &args.abbrev {
+            custom.push(abbrev::parse_entry(entry).unwrap_or_else(|e| stderr(&e)));
         }
 
-    }
+        abbrev::Shortener::new(max_len, custom)
+    });
 
-    if contains_underscore {
-        if is_lowercased {
-            return Case::Snake;
-        } else if word.to_uppercase() == word {
-            return Case::AllCaps;
-        }
+    let target = match &args.to {
+        Some(name) => Some(Target::resolve(name, custom).unwrap_or_else(|e| stderr(&e))),
+        None => args.case.target().map(Target::Case),
+    };
+
+    let mut pipeline = Pipeline {
+        target,
+        custom: custom.to_vec(),
+        preset: args.preset,
+        acronyms: args.acronym,
+        ascii: args.ascii,
+        lang,
+        escape: args.escape,
+        sanitize: args.sanitize.then_some(sanitize::Options {
+            leading_digit: args.leading_digit.unwrap_or(sanitize::LeadingDigit::Prefix),
+            prefix: args.digit_prefix.unwrap_or_else(|| "_".to_string()),
+        }),
+        shortener,
+        segmenter: args.segment.then(|| {
+            let words = match &args.dict {
+                Some(file) => read_input(&Some(file.clone()))
+                    .unwrap_or_else(|e| stderr(&e))
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(String::from)
+                    .collect(),
+                None => Vec::new(),
+            };
+
+            segment::Segmenter::new(&words)
+        }),
+    };
+
+    if let Some(word) = &args.word {
+        stdout(&pipeline.run(word).unwrap_or_else(|e| stderr(&e)));
     }
 
-    if contains_dash {
-        if is_lowercased {
-            return Case::Kebab;
-        } else if word.to_uppercase() == word {
-            return Case::Train;
-        } else if is_http_header_case(&word) {
-            return Case::HttpHeader;
+    let input = read_input(&None).unwrap_or_else(|e| stderr(&e));
+
+    for (i, line) in input.lines().enumerate() {
+        match pipeline.run(line) {
+            Ok(result) => println!("{}", result),
+            Err(e) => stderr(&format!("Line {}: {}", i + 1, e)),
         }
     }
 
-    return Case::None;
+    process::exit(0);
 }
 
-fn is_token_capitalized_case(word: &str, token: char) -> bool {
-    let mut first = true;
-    let mut found_token = false;
+/// Every step between a word and its output, kept across the lines of a
+/// batch so shortened names stay unique.
+struct Pipeline {
+    target: Option<Target>,
+    custom: Vec<CustomCase>,
+    preset: Option<Preset>,
+    acronyms: Vec<String>,
+    ascii: bool,
+    lang: Option<Language>,
+    escape: Option<Escape>,
+    sanitize: Option<sanitize::Options>,
+    shortener: Option<abbrev::Shortener>,
+    segmenter: Option<segment::Segmenter>,
+}
 
-    for c in word.chars() {
-        if first {
-            if !c.is_uppercase() || c == '-' || c == '_' || c == ' ' {
-                return false;
-            }
-            first = false;
-            continue;
+impl Pipeline {
+    fn run(&mut self, original: &str) -> Result<String, String> {
+        let word = match self.ascii {
+            true => ascii::transliterate(original),
+            false => original.to_string(),
+        };
+
+        if !word.contains(char::is_alphanumeric) {
+            return match original.contains(char::is_alphanumeric) {
+                true => Err(format!("Nothing is left of \"{}\"", original)),
+                false => Err("Invalid input".to_string()),
+            };
         }
 
-        if found_token {
-            if !c.is_uppercase() {
-                return false;
+        // Defined cases are more specific than the built in ones they may
+        // look like, as `k_hello` is also snake case.
+        let source = self.custom.iter().find_map(|custom| {
+            let words = custom.split(&word)?;
+            Some((custom.name.clone(), words))
+        });
+
+        let options =
+            self.sanitize.is_some() || self.shortener.is_some() || self.segmenter.is_some();
+
+        let target = match (self.preset, &self.target) {
+            (Some(preset), _) => Target::Case(preset.case()),
+            (None, Some(target)) => target.clone(),
+            (None, None) if options => return Err("No target case given".to_string()),
+            (None, None) => {
+                if let Some((name, _)) = source {
+                    return Ok(name);
+                }
+
+                return match get_case(&word) {
+                    Case::None => Err("Invalid input".to_string()),
+                    case => Ok(case.to_string()),
+                };
             }
-            found_token = false;
-            continue;
-        }
+        };
 
-        if c == token {
-            found_token = true;
-            continue;
-        }
+        if let Target::Case(to) = target {
+            if !options && self.preset.is_none() && source.is_none() {
+                let case = get_case(&word);
 
-        if c.is_uppercase() {
-            return false;
+                if case == Case::None {
+                    return Err("Invalid input".to_string());
+                }
+
+                return Ok(self.escape_keyword(convert(&word, case, to)));
+            }
         }
-    }
 
-    return true;
-}
+        let mut words = match (source, &self.sanitize) {
+            (Some((_, words)), _) => words,
+            (None, Some(options)) => options.words(&word),
+            (None, None) => split_words(&word),
+        };
+
+        if let Some(segmenter) = &self.segmenter {
+            words = words
+                .iter()
+                .flat_map(|word| {
+                    let segmentation = segmenter.segment(word);
+
+                    if let Some(alternative) = &segmentation.alternative {
+                        eprintln!(
+                            "\"{}\" is ambiguous, split as \"{}\" rather than \"{}\"",
+                            word,
+                            segmentation.words.join(" "),
+                            alternative.join(" ")
+                        );
+                    }
+
+                    segmentation.words
+                })
+                .collect();
+        }
 
-fn is_http_header_case(word: &str) -> bool {
-    is_token_capitalized_case(word, '-')
-}
+        let (preset, acronyms, sanitize) = (self.preset, &self.acronyms, &self.sanitize);
+        let join = |words: &[String]| {
+            let result = match preset {
+                Some(preset) => preset::join(words, preset, acronyms),
+                None => target.join(words),
+            };
 
-fn is_title_case(word: &str) -> bool {
-    is_token_capitalized_case(word, ' ')
-}
+            match sanitize {
+                Some(options) if !result.is_empty() => options.fix_start(result),
+                _ => result,
+            }
+        };
 
-fn capitalize_first_letter(word: &str) -> String {
-    let mut v: Vec<char> = word.chars().collect();
-    v[0] = v[0].to_uppercase().nth(0).unwrap();
-    return v.into_iter().collect();
-}
+        let result = match &mut self.shortener {
+            Some(shortener) => shortener.shorten(&words, join),
+            None => join(&words),
+        };
 
-fn lower_first_letter(word: &str) -> String {
-    let mut v: Vec<char> = word.chars().collect();
-    v[0] = v[0].to_lowercase().nth(0).unwrap();
-    return v.into_iter().collect();
-}
+        if result.is_empty() {
+            return Err(format!("Nothing is left of \"{}\"", original));
+        }
 
-fn flat_word(word: &str) -> String {
-    word.replace("-", "").replace("_", "").replace(" ", "")
+        Ok(self.escape_keyword(result))
+    }
+
+    /// Escapes a converted word that is a keyword of the language, telling so
+    /// on stderr.
+    fn escape_keyword(&self, word: String) -> String {
+        let lang = match self.lang {
+            Some(lang) => lang,
+            None => return word,
+        };
+
+        let escape = match &self.escape {
+            Some(escape) => escape.clone(),
+            None => lang.default_escape(),
+        };
+
+        match lang.escape(&word, &escape) {
+            Some(escaped) => {
+                eprintln!(
+                    "\"{}\" is a {} keyword, escaped as \"{}\"",
+                    word, lang, escaped
+                );
+                escaped
+            }
+            None => word,
+        }
+    }
 }
 
-fn flat_case(word: &str) -> String {
-    flat_word(word).to_lowercase()
-}
+fn detect_command(args: DetectArgs, custom: &[CustomCase]) {
+    // defined cases first, as with a word and no target
+    let detect = |word: &str| match custom.iter().find(|case| case.split(word).is_some()) {
+        Some(case) => case.name.clone(),
+        None => get_case(word).to_string(),
+    };
 
-fn upper_case(word: &str) -> String {
-    flat_word(word).to_uppercase()
-}
+    if !args.words.is_empty() {
+        for word in &args.words {
+            println!("{}", detect(word));
+        }
 
-fn camel_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Camel => return word.to_string(),
-        Case::Pascal => return lower_first_letter(word),
-        Case::Flat => return word.to_string(),
-        Case::Upper => return word.to_string().to_lowercase(),
-        _ => (),
+        process::exit(0);
     }
 
-    let mut result = String::new();
-    let mut first = true;
+    let input = read_input(&None).unwrap_or_else(|e| stderr(&e));
 
-    for part in word.split(|c| c == '-' || c == '_' || c == ' ') {
-        if first {
-            result.push_str(&part.to_lowercase());
-            first = false;
-        } else {
-            result.push_str(&part[..1].to_uppercase());
-            result.push_str(&part[1..].to_lowercase());
-        }
+    for line in input.lines() {
+        println!("{}", detect(line));
     }
 
-    lower_first_letter(&result)
+    process::exit(0);
 }
 
-fn pascal_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Camel => return capitalize_first_letter(word),
-        Case::Pascal => return word.to_string(),
-        Case::Flat => return capitalize_first_letter(word),
-        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
-        _ => capitalize_first_letter(&camel_case(word, case)),
+/// The case given by name with `--to` or by a case flag.
+fn target(to: &Option<String>, case: &CaseArgs, custom: &[CustomCase]) -> Option<Target> {
+    match (to, case.target()) {
+        (Some(name), _) => Some(Target::resolve(name, custom).unwrap_or_else(|e| stderr(&e))),
+        (None, Some(case)) => Some(Target::Case(case)),
+        (None, None) => None,
     }
 }
 
-fn camel_or_pascal_to_token(word: &str, token: char) -> String {
-    let mut result = String::new();
-    let mut first = true;
+fn rename_command(args: RenameArgs, custom: &[CustomCase]) {
+    let target = match target(&args.to, &args.case, custom) {
+        Some(target) => target,
+        None => stderr("No target case given"),
+    };
 
-    for c in word.chars() {
-        if first {
-            result.push(c.to_lowercase().nth(0).unwrap());
-            first = false;
-            continue;
-        }
+    let renames = rename::plan(&args.files, &target).unwrap_or_else(|e| stderr(&e));
 
-        if c.is_uppercase() {
-            result.push(token);
-            result.push(c.to_lowercase().nth(0).unwrap());
-        } else {
-            result.push(c);
+    for rename in &renames {
+        println!("{} -> {}", rename.from.display(), rename.to.display());
+
+        if !args.dry_run {
+            fs::rename(&rename.from, &rename.to).unwrap_or_else(|e| {
+                stderr(&format!(
+                    "Could not rename {}: {}",
+                    rename.from.display(),
+                    e
+                ))
+            });
         }
     }
 
-    result
+    process::exit(0);
 }
 
-fn camel_or_pascal_to_upper_token(word: &str, token: char) -> String {
-    let mut result = String::new();
-    let mut first = true;
+fn slug_command(args: ConvertArgs) {
+    let mut slugger = slug::Slugger::new(slug::Options {
+        max_len: args.max_len,
+        stop_words: args.stop_words,
+        unique: args.unique,
+    });
 
-    for c in word.chars() {
-        if first {
-            result.push(c.to_uppercase().nth(0).unwrap());
-            first = false;
-            continue;
-        }
+    if let Some(word) = &args.word {
+        let slug = slugger.slug(word);
 
-        if c.is_uppercase() {
-            result.push(token);
+        if slug.is_empty() {
+            stderr(&format!("Nothing is left of \"{}\" for a slug", word));
         }
 
-        result.push(c);
+        stdout(&slug);
     }
 
-    result
-}
+    let input = read_input(&None).unwrap_or_else(|e| stderr(&e));
 
-fn snake_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Snake => return word.to_string(),
-        Case::AllCaps => return word.to_lowercase(),
-        Case::Flat => return word.to_string(),
-        Case::Upper => return word.to_lowercase(),
-        Case::Kebab => return word.replace("-", "_"),
-        Case::Train => return word.replace("-", "_").to_lowercase(),
-        Case::Spaced => return word.replace(" ", "_").to_lowercase(),
-        Case::Title => return word.replace(" ", "_").to_lowercase(),
-        Case::HttpHeader => return word.replace("-", "_").to_lowercase(),
-        _ => camel_or_pascal_to_token(word, '_'),
+    for line in input.lines() {
+        println!("{}", slugger.slug(line));
     }
-}
 
-fn all_caps_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Snake => return word.to_uppercase(),
-        Case::AllCaps => return word.to_string(),
-        Case::Flat => return word.to_uppercase(),
-        Case::Upper => return word.to_string(),
-        Case::Kebab => return word.replace("-", "_").to_uppercase(),
-        Case::Train => return word.replace("-", "_"),
-        Case::Spaced => return word.replace(" ", "_").to_uppercase(),
-        Case::Title => return word.replace(" ", "_").to_uppercase(),
-        Case::HttpHeader => return word.replace("-", "_").to_uppercase(),
-        _ => camel_or_pascal_to_token(word, '_').to_uppercase(),
-    }
+    process::exit(0);
 }
 
-fn kebab_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Snake => return word.replace("_", "-"),
-        Case::AllCaps => return word.replace("_", "-").to_lowercase(),
-        Case::Flat => return word.to_string(),
-        Case::Upper => return word.to_lowercase(),
-        Case::Kebab => return word.to_string(),
-        Case::Train => return word.to_lowercase(),
-        Case::Spaced => return word.replace(" ", "-"),
-        Case::Title => return word.replace(" ", "-").to_lowercase(),
-        Case::HttpHeader => return word.to_lowercase(),
-        _ => camel_or_pascal_to_token(word, '-'),
-    }
-}
+fn json_command(args: JsonArgs, custom: &[CustomCase]) {
+    let to = match target(&args.to, &args.case, custom) {
+        Some(to) => to,
+        None => stderr("No target case given"),
+    };
 
-fn train_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Snake => return word.replace("_", "-").to_uppercase(),
-        Case::AllCaps => return word.replace("_", "-"),
-        Case::Flat => return word.to_uppercase(),
-        Case::Upper => return word.to_string(),
-        Case::Kebab => return word.to_uppercase(),
-        Case::Train => return word.to_string(),
-        Case::Spaced => return word.replace(" ", "-").to_uppercase(),
-        Case::Title => return word.replace(" ", "-").to_uppercase(),
-        Case::HttpHeader => return word.replace("_", "-").to_uppercase(),
-        _ => camel_or_pascal_to_token(word, '-').to_uppercase(),
-    }
-}
+    let parse_pointers = |pointers: &[String]| -> Vec<Vec<String>> {
+        pointers
+            .iter()
+            .map(|pointer| json::parse_pointer(pointer).unwrap_or_else(|e| stderr(&e)))
+            .collect()
+    };
+
+    let options = json::Options {
+        to,
+        include: parse_pointers(&args.include),
+        exclude: parse_pointers(&args.exclude),
+        values: args.values,
+        strict: args.strict,
+    };
+
+    let input = read_input(&args.file).unwrap_or_else(|e| stderr(&e));
 
-fn spaced_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Snake => return word.replace("_", " "),
-        Case::AllCaps => return word.replace("_", " ").to_lowercase(),
-        Case::Flat => return word.to_string(),
-        Case::Upper => return word.to_lowercase(),
-        Case::Kebab => return word.replace("-", " "),
-        Case::Train => return word.replace("-", " ").to_lowercase(),
-        Case::Spaced => return word.to_string(),
-        Case::Title => return word.to_lowercase(),
-        Case::HttpHeader => return word.replace("-", " ").to_lowercase(),
-        _ => camel_or_pascal_to_token(word, ' '),
+    let value: serde_json::Value = match serde_json::from_str(&input) {
+        Ok(value) => value,
+        Err(e) => stderr(&format!("Invalid json: {}", e)),
+    };
+
+    let (value, collisions) = json::transform(value, &options).unwrap_or_else(|e| stderr(&e));
+
+    for collision in &collisions {
+        eprintln!(
+            "{}, keeping the value of \"{}\"",
+            collision, collision.second
+        );
     }
+
+    stdout(&serde_json::to_string_pretty(&value).unwrap());
 }
 
-fn token_to_token_capitalized_case(word: &str, token: char) -> String {
-    let mut result = String::new();
-    let mut first = true;
-
-    for part in word
-        .to_lowercase()
-        .split(|c| c == '-' || c == '_' || c == ' ')
-    {
-        if first {
-            result.push_str(&part[..1].to_uppercase());
-            result.push_str(&part[1..].to_lowercase());
-            first = false;
-        } else {
-            result.push(token);
-            result.push_str(&part[..1].to_uppercase());
-            result.push_str(&part[1..].to_lowercase());
+fn keys_command(args: KeysArgs, format: keys::Format, custom: &[CustomCase]) {
+    let to = match target(&args.to, &args.case, custom) {
+        Some(to) => to,
+        None => stderr("No target case given"),
+    };
+
+    let input = read_input(&args.file).unwrap_or_else(|e| stderr(&e));
+
+    if !args.check {
+        match keys::rewrite(&input, format, &to) {
+            Ok(output) => {
+                print!("{}", output);
+                process::exit(0);
+            }
+            Err(e) => stderr(&e),
         }
     }
 
-    result
+    let offenders =
+        check::find_offenders(&input, format.into(), &to).unwrap_or_else(|e| stderr(&e));
+
+    check::report(
+        &file_name(&args.file),
+        &offenders,
+        &to,
+        check::Style::Compiler,
+    );
+
+    process::exit(if offenders.is_empty() { 0 } else { 1 });
 }
 
-fn title_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Flat => return capitalize_first_letter(&word),
-        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
-        Case::Camel => return camel_or_pascal_to_upper_token(word, ' '),
-        Case::Pascal => return camel_or_pascal_to_upper_token(word, ' '),
-        _ => token_to_token_capitalized_case(word, ' '),
+fn check_command(args: CheckArgs, custom: &[CustomCase]) {
+    let expect = Target::resolve(&args.expect, custom).unwrap_or_else(|e| stderr(&e));
+    let mut offenders = 0;
+
+    for word in &args.word {
+        if let Some((case, fix)) = check::check_word(word, &expect) {
+            let offender = check::Offender {
+                line: 1,
+                column: 1,
+                word: word.to_string(),
+                case,
+                fix,
+            };
+
+            check::report("<word>", &[offender], &expect, args.style);
+            offenders += 1;
+        }
     }
-}
 
-fn http_header_case(word: &str, case: Case) -> String {
-    match case {
-        Case::Flat => return capitalize_first_letter(&word),
-        Case::Upper => return capitalize_first_letter(&word.to_lowercase()),
-        Case::Camel => return camel_or_pascal_to_upper_token(word, '-'),
-        Case::Pascal => return camel_or_pascal_to_upper_token(word, '-'),
-        _ => token_to_token_capitalized_case(word, '-'),
+    let files: Vec<Option<PathBuf>> = if args.files.is_empty() && args.word.is_empty() {
+        vec![None]
+    } else {
+        args.files.into_iter().map(Some).collect()
+    };
+
+    for file in &files {
+        let input = read_input(file).unwrap_or_else(|e| stderr(&e));
+        let found = check::find_offenders(&input, args.mode, &expect)
+            .unwrap_or_else(|e| stderr(&format!("{}: {}", file_name(file), e)));
+
+        check::report(&file_name(file), &found, &expect, args.style);
+        offenders += found.len();
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static FLAT: &str = "helloworld";
-    static UPPER: &str = "HELLOWORLD";
-    static CAMEL: &str = "helloWorld";
-    static BROKEN_PASCAL: &str = "Helloworld";
-    static PASCAL: &str = "HelloWorld";
-    static SNAKE: &str = "hello_world";
-    static ALL_CAPS: &str = "HELLO_WORLD";
-    static KEBAB: &str = "hello-world";
-    static TRAIN: &str = "HELLO-WORLD";
-    static SPACED: &str = "hello world";
-    static TITLE: &str = "Hello World";
-    static HTTP_HEADER: &str = "Hello-World";
-
-    #[test]
-    fn test_flat_case() {
-        assert_eq!(flat_case(FLAT), FLAT);
-        assert_eq!(flat_case(UPPER), FLAT);
-        assert_eq!(flat_case(CAMEL), FLAT);
-        assert_eq!(flat_case(PASCAL), FLAT);
-        assert_eq!(flat_case(SNAKE), FLAT);
-        assert_eq!(flat_case(ALL_CAPS), FLAT);
-        assert_eq!(flat_case(KEBAB), FLAT);
-        assert_eq!(flat_case(TRAIN), FLAT);
-        assert_eq!(flat_case(SPACED), FLAT);
-        assert_eq!(flat_case(TITLE), FLAT);
-        assert_eq!(flat_case(HTTP_HEADER), FLAT);
+    if offenders == 0 {
+        process::exit(0);
     }
 
-    #[test]
-    fn test_upper_case() {
-        assert_eq!(upper_case(FLAT), UPPER);
-        assert_eq!(upper_case(UPPER), UPPER);
-        assert_eq!(upper_case(CAMEL), UPPER);
-        assert_eq!(upper_case(PASCAL), UPPER);
-        assert_eq!(upper_case(SNAKE), UPPER);
-        assert_eq!(upper_case(ALL_CAPS), UPPER);
-        assert_eq!(upper_case(KEBAB), UPPER);
-        assert_eq!(upper_case(TRAIN), UPPER);
-        assert_eq!(upper_case(SPACED), UPPER);
-        assert_eq!(upper_case(TITLE), UPPER);
-        assert_eq!(upper_case(HTTP_HEADER), UPPER);
+    if args.style == check::Style::Human {
+        println!("\n{} word(s) not in {} case", offenders, expect);
     }
 
-    #[test]
-    fn test_camel_case() {
-        fn camel_case_helper(word: &str) -> String {
-            camel_case(word, get_case(word))
-        }
+    process::exit(1);
+}
 
-        assert_eq!(camel_case_helper(FLAT), FLAT);
-        assert_eq!(camel_case_helper(UPPER), FLAT);
-        assert_eq!(camel_case_helper(CAMEL), CAMEL);
-        assert_eq!(camel_case_helper(PASCAL), CAMEL);
-        assert_eq!(camel_case_helper(SNAKE), CAMEL);
-        assert_eq!(camel_case_helper(ALL_CAPS), CAMEL);
-        assert_eq!(camel_case_helper(KEBAB), CAMEL);
-        assert_eq!(camel_case_helper(TRAIN), CAMEL);
-        assert_eq!(camel_case_helper(SPACED), CAMEL);
-        assert_eq!(camel_case_helper(TITLE), CAMEL);
-        assert_eq!(camel_case_helper(HTTP_HEADER), CAMEL);
-    }
+fn csv_command(args: CsvArgs, custom: &[CustomCase]) {
+    let to = match target(&args.to, &args.case, custom) {
+        Some(to) => to,
+        None => stderr("No target case given"),
+    };
 
-    #[test]
-    fn test_pascal_case() {
-        fn pascal_case_helper(word: &str) -> String {
-            pascal_case(word, get_case(word))
-        }
+    let delimiter = if args.tsv { '\t' } else { args.delimiter };
 
-        assert_eq!(pascal_case_helper(FLAT), BROKEN_PASCAL);
-        assert_eq!(pascal_case_helper(UPPER), BROKEN_PASCAL);
-        assert_eq!(pascal_case_helper(CAMEL), PASCAL);
-        assert_eq!(pascal_case_helper(PASCAL), PASCAL);
-        assert_eq!(pascal_case_helper(SNAKE), PASCAL);
-        assert_eq!(pascal_case_helper(ALL_CAPS), PASCAL);
-        assert_eq!(pascal_case_helper(KEBAB), PASCAL);
-        assert_eq!(pascal_case_helper(TRAIN), PASCAL);
-        assert_eq!(pascal_case_helper(SPACED), PASCAL);
-        assert_eq!(pascal_case_helper(TITLE), PASCAL);
-        assert_eq!(pascal_case_helper(HTTP_HEADER), PASCAL);
+    if !delimiter.is_ascii() {
+        stderr("The delimiter must be an ascii character");
     }
 
-    #[test]
-    fn test_snake_case() {
-        fn snake_case_helper(word: &str) -> String {
-            snake_case(word, get_case(word))
-        }
+    let options = table::Options {
+        to,
+        delimiter: delimiter as u8,
+        column: args.column,
+        ascii: args.ascii,
+    };
 
-        assert_eq!(snake_case_helper(FLAT), FLAT);
-        assert_eq!(snake_case_helper(UPPER), FLAT);
-        assert_eq!(snake_case_helper(CAMEL), SNAKE);
-        assert_eq!(snake_case_helper(PASCAL), SNAKE);
-        assert_eq!(snake_case_helper(SNAKE), SNAKE);
-        assert_eq!(snake_case_helper(ALL_CAPS), SNAKE);
-        assert_eq!(snake_case_helper(KEBAB), SNAKE);
-        assert_eq!(snake_case_helper(TRAIN), SNAKE);
-        assert_eq!(snake_case_helper(SPACED), SNAKE);
-        assert_eq!(snake_case_helper(TITLE), SNAKE);
-        assert_eq!(snake_case_helper(HTTP_HEADER), SNAKE);
-    }
+    let input = read_input(&args.file).unwrap_or_else(|e| stderr(&e));
+    let (output, duplicates) = table::transform(&input, &options).unwrap_or_else(|e| stderr(&e));
 
-    #[test]
-    fn test_all_caps_case() {
-        fn all_caps_case_helper(word: &str) -> String {
-            all_caps_case(word, get_case(word))
-        }
+    for duplicate in &duplicates {
+        let columns: Vec<String> = duplicate.columns.iter().map(|c| c.to_string()).collect();
 
-        assert_eq!(all_caps_case_helper(FLAT), UPPER);
-        assert_eq!(all_caps_case_helper(UPPER), UPPER);
-        assert_eq!(all_caps_case_helper(CAMEL), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(PASCAL), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(SNAKE), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(ALL_CAPS), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(KEBAB), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(TRAIN), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(SPACED), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(TITLE), ALL_CAPS);
-        assert_eq!(all_caps_case_helper(HTTP_HEADER), ALL_CAPS);
+        eprintln!(
+            "Duplicate header \"{}\" in columns {}",
+            duplicate.header,
+            columns.join(", ")
+        );
     }
 
-    #[test]
-    fn test_kebab_case() {
-        fn kebab_case_helper(word: &str) -> String {
-            kebab_case(word, get_case(word))
-        }
-
-        assert_eq!(kebab_case_helper(FLAT), FLAT);
-        assert_eq!(kebab_case_helper(UPPER), FLAT);
-        assert_eq!(kebab_case_helper(CAMEL), KEBAB);
-        assert_eq!(kebab_case_helper(PASCAL), KEBAB);
-        assert_eq!(kebab_case_helper(SNAKE), KEBAB);
-        assert_eq!(kebab_case_helper(ALL_CAPS), KEBAB);
-        assert_eq!(kebab_case_helper(KEBAB), KEBAB);
-        assert_eq!(kebab_case_helper(TRAIN), KEBAB);
-        assert_eq!(kebab_case_helper(SPACED), KEBAB);
-        assert_eq!(kebab_case_helper(TITLE), KEBAB);
-        assert_eq!(kebab_case_helper(HTTP_HEADER), KEBAB);
+    if args.strict && !duplicates.is_empty() {
+        process::exit(1);
     }
 
-    #[test]
-    fn test_train_case() {
-        fn train_case_helper(word: &str) -> String {
-            train_case(word, get_case(word))
+    print!("{}", output);
+    process::exit(0);
+}
+
+fn env_command(args: EnvArgs, custom: &[CustomCase]) {
+    let to = target(&args.to, &args.case, custom).unwrap_or(Target::Case(Case::Camel));
+
+    let options = env::Options {
+        prefix: args.prefix,
+        separator: args.separator,
+    };
+
+    let rename = |key: &str| -> Result<String, String> {
+        if args.reverse {
+            env::from_env_name(key, &to, &options)
+        } else {
+            Ok(env::to_env_name(key, &options))
         }
+    };
 
-        assert_eq!(train_case_helper(FLAT), UPPER);
-        assert_eq!(train_case_helper(UPPER), UPPER);
-        assert_eq!(train_case_helper(CAMEL), TRAIN);
-        assert_eq!(train_case_helper(PASCAL), TRAIN);
-        assert_eq!(train_case_helper(SNAKE), TRAIN);
-        assert_eq!(train_case_helper(ALL_CAPS), TRAIN);
-        assert_eq!(train_case_helper(KEBAB), TRAIN);
-        assert_eq!(train_case_helper(TRAIN), TRAIN);
-        assert_eq!(train_case_helper(SPACED), TRAIN);
-        assert_eq!(train_case_helper(TITLE), TRAIN);
-        assert_eq!(train_case_helper(HTTP_HEADER), TRAIN);
+    if args.keys.is_empty() {
+        let input = read_input(&args.file).unwrap_or_else(|e| stderr(&e));
+        let output = env::rewrite(&input, |key| {
+            rename(key)
+                .map_err(|e| eprintln!("{}, keeping the key as it is", e))
+                .ok()
+        });
+        print!("{}", output);
+        process::exit(0);
     }
 
-    #[test]
-    fn test_spaced_case() {
-        fn spaced_case_helper(word: &str) -> String {
-            spaced_case(word, get_case(word))
-        }
+    let mut names = Vec::new();
 
-        assert_eq!(spaced_case_helper(FLAT), FLAT);
-        assert_eq!(spaced_case_helper(UPPER), FLAT);
-        assert_eq!(spaced_case_helper(CAMEL), SPACED);
-        assert_eq!(spaced_case_helper(PASCAL), SPACED);
-        assert_eq!(spaced_case_helper(SNAKE), SPACED);
-        assert_eq!(spaced_case_helper(ALL_CAPS), SPACED);
-        assert_eq!(spaced_case_helper(KEBAB), SPACED);
-        assert_eq!(spaced_case_helper(TRAIN), SPACED);
-        assert_eq!(spaced_case_helper(SPACED), SPACED);
-        assert_eq!(spaced_case_helper(TITLE), SPACED);
-        assert_eq!(spaced_case_helper(HTTP_HEADER), SPACED);
+    for key in &args.keys {
+        names.push(rename(key).unwrap_or_else(|e| stderr(&e)));
     }
 
-    #[test]
-    fn test_title_case() {
-        fn title_case_helper(word: &str) -> String {
-            title_case(word, get_case(word))
-        }
+    stdout(&names.join("\n"));
+}
 
-        assert_eq!(title_case_helper(FLAT), BROKEN_PASCAL);
-        assert_eq!(title_case_helper(UPPER), BROKEN_PASCAL);
-        assert_eq!(title_case_helper(CAMEL), TITLE);
-        assert_eq!(title_case_helper(PASCAL), TITLE);
-        assert_eq!(title_case_helper(SNAKE), TITLE);
-        assert_eq!(title_case_helper(ALL_CAPS), TITLE);
-        assert_eq!(title_case_helper(KEBAB), TITLE);
-        assert_eq!(title_case_helper(TRAIN), TITLE);
-        assert_eq!(title_case_helper(SPACED), TITLE);
-        assert_eq!(title_case_helper(TITLE), TITLE);
-        assert_eq!(title_case_helper(HTTP_HEADER), TITLE);
+fn files_command(args: FilesArgs, custom: &[CustomCase]) {
+    let to = match target(&args.to, &args.case, custom) {
+        Some(to) => to,
+        None => stderr("No target case given"),
+    };
+
+    if args.files.len() > 1 && !args.in_place {
+        stderr("Use --in-place to transform several files");
     }
 
-    #[test]
-    fn test_http_header_case() {
-        fn http_header_case_helper(word: &str) -> String {
-            http_header_case(word, get_case(word))
+    let files: Vec<Option<PathBuf>> = if args.files.is_empty() {
+        vec![None]
+    } else {
+        args.files.into_iter().map(Some).collect()
+    };
+
+    for file in &files {
+        let lang = match (args.lang, file) {
+            (Some(lang), _) => lang,
+            (None, Some(path)) => Language::from_path(path).unwrap_or_else(|| {
+                stderr(&format!(
+                    "Unknown language of {}, use --lang",
+                    path.display()
+                ))
+            }),
+            (None, None) => stderr("Use --lang when reading from stdin"),
+        };
+
+        let options = files::Options {
+            lang,
+            from: args.from,
+            to: to.clone(),
+            strings: args.strings,
+            comments: args.comments,
+            escape: args.escape.clone(),
+        };
+
+        let input = read_input(file).unwrap_or_else(|e| stderr(&e));
+        let (output, collisions) = files::rewrite(&input, &options);
+
+        for collision in &collisions {
+            let action = match &collision.escaped {
+                Some(escaped) => format!("renamed \"{}\" to \"{}\"", collision.word, escaped),
+                None => format!("kept \"{}\"", collision.word),
+            };
+
+            eprintln!(
+                "{}:{}:{}: {}, \"{}\" is a {} keyword",
+                file_name(file),
+                collision.line,
+                collision.column,
+                action,
+                collision.keyword,
+                lang
+            );
         }
 
-        assert_eq!(http_header_case_helper(FLAT), BROKEN_PASCAL);
-        assert_eq!(http_header_case_helper(UPPER), BROKEN_PASCAL);
-        assert_eq!(http_header_case_helper(CAMEL), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(PASCAL), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(SNAKE), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(ALL_CAPS), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(KEBAB), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(TRAIN), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(SPACED), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(TITLE), HTTP_HEADER);
-        assert_eq!(http_header_case_helper(HTTP_HEADER), HTTP_HEADER);
+        match file {
+            Some(path) if args.in_place => fs::write(path, output)
+                .unwrap_or_else(|e| stderr(&format!("Could not write {}: {}", path.display(), e))),
+            _ => print!("{}", output),
+        }
     }
 
-    #[test]
-    fn test_get_case() {
-        assert_eq!(get_case(FLAT), Case::Flat);
-        assert_eq!(get_case(UPPER), Case::Upper);
-        assert_eq!(get_case(CAMEL), Case::Camel);
-        assert_eq!(get_case(PASCAL), Case::Pascal);
-        assert_eq!(get_case(SNAKE), Case::Snake);
-        assert_eq!(get_case(ALL_CAPS), Case::AllCaps);
-        assert_eq!(get_case(KEBAB), Case::Kebab);
-        assert_eq!(get_case(TRAIN), Case::Train);
-        assert_eq!(get_case(SPACED), Case::Spaced);
-        assert_eq!(get_case(TITLE), Case::Title);
-        assert_eq!(get_case(HTTP_HEADER), Case::HttpHeader);
-
-        assert_eq!(get_case("hello-new_world"), Case::None);
-        assert_eq!(get_case("hello-World"), Case::None);
-        assert_eq!(get_case("hello new-world"), Case::None);
-        assert_eq!(get_case("hello_new-world of programming"), Case::None);
-        assert_eq!(get_case("Broken-HttP-Header"), Case::None);
-        assert_eq!(get_case("Broken space case"), Case::None);
-    }
+    process::exit(0);
 }
@@ -0,0 +1,173 @@
+use crate::lang::Language;
+use crate::{join_words, Case};
+use clap::ValueEnum;
+
+/// Naming conventions of common languages and formats.
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Preset {
+    /// structs, enums and traits: `HttpServer`
+    RustType,
+    /// functions and methods: `parse_header`
+    RustFn,
+    /// variables and fields: `max_size`
+    RustVar,
+    /// constants and statics: `MAX_SIZE`
+    RustConst,
+    /// classes: `HttpServer`
+    PythonClass,
+    /// functions, methods and variables: `parse_header`
+    PythonFn,
+    /// module level constants: `MAX_SIZE`
+    PythonConst,
+    /// exported names: `HTTPServer`, `UserID`
+    GoExported,
+    /// unexported names: `httpServer`, `userID`
+    GoUnexported,
+    /// classes: `HttpServer`
+    JsClass,
+    /// functions and variables: `parseHeader`
+    JsFn,
+    /// types and interfaces: `HttpServer`
+    TsType,
+    /// class names: `nav-bar`
+    CssClass,
+    /// tables: `user_account`
+    SqlTable,
+    /// columns: `created_at`
+    SqlColumn,
+    /// header names: `Content-Type`
+    HttpHeader,
+    /// preprocessor macros: `MAX_SIZE`
+    CMacro,
+    /// environment variables: `DATABASE_URL`
+    EnvVar,
+}
+
+/// Initialisms go keeps in a single case, from the go code review comments.
+const GO_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL",
+    "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+impl Preset {
+    pub fn case(&self) -> Case {
+        match self {
+            Preset::RustType | Preset::PythonClass | Preset::JsClass | Preset::TsType => {
+                Case::Pascal
+            }
+            Preset::GoExported => Case::Pascal,
+            Preset::GoUnexported | Preset::JsFn => Case::Camel,
+            Preset::RustFn | Preset::RustVar | Preset::PythonFn => Case::Snake,
+            Preset::SqlTable | Preset::SqlColumn => Case::Snake,
+            Preset::RustConst | Preset::PythonConst | Preset::CMacro | Preset::EnvVar => {
+                Case::AllCaps
+            }
+            Preset::CssClass => Case::Kebab,
+            Preset::HttpHeader => Case::HttpHeader,
+        }
+    }
+
+    /// Language whose keywords the result must not collide with.
+    pub fn language(&self) -> Option<Language> {
+        match self {
+            Preset::RustType | Preset::RustFn | Preset::RustVar | Preset::RustConst => {
+                Some(Language::Rust)
+            }
+            Preset::PythonClass | Preset::PythonFn | Preset::PythonConst => Some(Language::Python),
+            Preset::GoExported | Preset::GoUnexported => Some(Language::Go),
+            Preset::JsClass | Preset::JsFn => Some(Language::Javascript),
+            Preset::TsType => Some(Language::Typescript),
+            Preset::SqlTable | Preset::SqlColumn => Some(Language::Sql),
+            Preset::CssClass | Preset::HttpHeader | Preset::CMacro | Preset::EnvVar => None,
+        }
+    }
+
+    fn upper_initialisms(&self) -> bool {
+        matches!(self, Preset::GoExported | Preset::GoUnexported)
+    }
+}
+
+/// Joins words in the convention of the preset, `acronyms` are initialisms
+/// kept in upper case besides the built-in ones.
+pub fn join(words: &[String], preset: Preset, acronyms: &[String]) -> String {
+    if preset.upper_initialisms() {
+        return join_with_initialisms(words, preset.case(), acronyms);
+    }
+
+    join_words(words, preset.case())
+}
+
+fn join_with_initialisms(words: &[String], case: Case, acronyms: &[String]) -> String {
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if i == 0 && case == Case::Camel {
+                word.to_lowercase()
+            } else if is_initialism(word, acronyms) {
+                word.to_uppercase()
+            } else {
+                join_words(std::slice::from_ref(word), Case::Pascal)
+            }
+        })
+        .collect()
+}
+
+fn is_initialism(word: &str, acronyms: &[String]) -> bool {
+    let upper = word.to_uppercase();
+
+    GO_INITIALISMS.contains(&upper.as_str())
+        || acronyms
+            .iter()
+            .any(|acronym| acronym.to_uppercase() == upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::split_words;
+
+    fn apply(text: &str, preset: Preset) -> String {
+        join(&split_words(text), preset, &[])
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(apply("http server", Preset::RustType), "HttpServer");
+        assert_eq!(apply("HTTPServer", Preset::RustFn), "http_server");
+        assert_eq!(apply("max-size", Preset::PythonConst), "MAX_SIZE");
+        assert_eq!(apply("user_id", Preset::GoExported), "UserID");
+        assert_eq!(
+            apply("http_server_url", Preset::GoUnexported),
+            "httpServerURL"
+        );
+        assert_eq!(apply("Nav Bar", Preset::CssClass), "nav-bar");
+        assert_eq!(apply("createdAt", Preset::SqlColumn), "created_at");
+        assert_eq!(apply("content type", Preset::HttpHeader), "Content-Type");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        let acronyms = vec!["Sdk".to_string()];
+        let words = split_words("aws_sdk_client");
+
+        assert_eq!(join(&words, Preset::GoExported, &acronyms), "AwsSDKClient");
+        assert_eq!(join(&words, Preset::RustType, &acronyms), "AwsSdkClient");
+    }
+
+    #[test]
+    fn test_apply_escapes_keywords() {
+        let escaped = |text, preset: Preset| {
+            let lang = preset.language().unwrap();
+            lang.escape(&apply(text, preset), &lang.default_escape())
+        };
+
+        assert_eq!(escaped("Type", Preset::RustFn).unwrap(), "r#type");
+        assert_eq!(escaped("SELF", Preset::RustVar).unwrap(), "self_");
+        assert_eq!(escaped("Class", Preset::PythonFn).unwrap(), "class_");
+        assert_eq!(escaped("Type", Preset::GoUnexported).unwrap(), "type_");
+        assert_eq!(escaped("User", Preset::SqlTable).unwrap(), "\"user\"");
+        assert_eq!(escaped("Name", Preset::RustFn), None);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::custom::Target;
+use crate::split_words;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A file or directory to rename.
+#[derive(Debug, PartialEq)]
+pub struct Rename {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// The name with the part before its first dot in `target`, keeping the
+/// extensions. `None` when it doesn't change or has no such part, like
+/// `.gitignore`.
+pub fn new_name(name: &str, target: &Target) -> Option<String> {
+    let (stem, extension) = match name.find('.') {
+        Some(0) => return None,
+        Some(i) => name.split_at(i),
+        None => (name, ""),
+    };
+
+    let words = split_words(stem);
+
+    if words.is_empty() {
+        return None;
+    }
+
+    let renamed = format!("{}{}", target.join(&words), extension);
+    (renamed != name).then_some(renamed)
+}
+
+/// Whether a directory entry has exactly this name, which `Path::exists`
+/// can't tell on case insensitive file systems.
+fn is_taken(path: &Path) -> bool {
+    let (parent, name) = match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => (parent, name),
+        _ => return path.exists(),
+    };
+
+    let parent = match parent.as_os_str().is_empty() {
+        true => Path::new("."),
+        false => parent,
+    };
+
+    fs::read_dir(parent)
+        .map(|entries| entries.flatten().any(|entry| entry.file_name() == name))
+        .unwrap_or(false)
+}
+
+/// The renames of `files`, deepest first so that renaming a directory comes
+/// after renaming what it contains. Fails on a new name that is already
+/// taken, rather than overwriting a file.
+pub fn plan(files: &[PathBuf], target: &Target) -> Result<Vec<Rename>, String> {
+    let mut renames = Vec::new();
+    let mut taken = HashSet::new();
+
+    for file in files {
+        if fs::symlink_metadata(file).is_err() {
+            return Err(format!("Could not find {}", file.display()));
+        }
+
+        let name = match file.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let to = match new_name(name, target) {
+            Some(new_name) => file.with_file_name(new_name),
+            None => continue,
+        };
+
+        if is_taken(&to) || !taken.insert(to.clone()) {
+            return Err(format!(
+                "Could not rename {} to {}, the name is taken",
+                file.display(),
+                to.display()
+            ));
+        }
+
+        renames.push(Rename {
+            from: file.clone(),
+            to,
+        });
+    }
+
+    renames.sort_by_key(|rename| std::cmp::Reverse(rename.from.components().count()));
+    Ok(renames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Case;
+    use std::env;
+
+    #[test]
+    fn test_new_name() {
+        let snake = Target::Case(Case::Snake);
+
+        assert_eq!(
+            new_name("MyReport.tar.gz", &snake),
+            Some("my_report.tar.gz".to_string())
+        );
+        assert_eq!(
+            new_name("E-mail Address", &snake),
+            Some("e_mail_address".to_string())
+        );
+        assert_eq!(new_name("my_report.txt", &snake), None);
+        assert_eq!(new_name(".gitignore", &snake), None);
+        assert_eq!(
+            new_name("my_report", &Target::Case(Case::Kebab)),
+            Some("my-report".to_string())
+        );
+    }
+
+    #[test]
+    fn test_plan() {
+        let root = env::temp_dir().join(format!("wcase-rename-{}", std::process::id()));
+        let dir = root.join("Some Dir");
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("firstFile.txt"), "").unwrap();
+        fs::write(dir.join("second_file.txt"), "").unwrap();
+        fs::write(dir.join("SecondFile.txt"), "").unwrap();
+
+        let snake = Target::Case(Case::Snake);
+        let plan_of = |names: &[&str]| {
+            let files: Vec<PathBuf> = names.iter().map(|name| root.join(name)).collect();
+            plan(&files, &snake)
+        };
+
+        let renames = plan_of(&["Some Dir", "Some Dir/firstFile.txt"]);
+        let taken = plan_of(&["Some Dir/SecondFile.txt"]);
+        let missing = plan_of(&["missing.txt"]);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            renames.unwrap(),
+            vec![
+                Rename {
+                    from: dir.join("firstFile.txt"),
+                    to: dir.join("first_file.txt"),
+                },
+                Rename {
+                    from: dir.clone(),
+                    to: root.join("some_dir"),
+                },
+            ]
+        );
+        assert!(taken.unwrap_err().contains("the name is taken"));
+        assert!(missing.unwrap_err().starts_with("Could not find"));
+    }
+}
@@ -0,0 +1,157 @@
+use clap::ValueEnum;
+
+/// Drops apostrophes inside words, so `printer's` stays a single word.
+pub fn drop_apostrophes(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+
+    for (i, c) in chars.iter().enumerate() {
+        let is_apostrophe = matches!(c, '\'' | '’')
+            && i > 0
+            && chars[i - 1].is_alphanumeric()
+            && chars.get(i + 1).is_some_and(|n| n.is_alphanumeric());
+
+        if !is_apostrophe {
+            result.push(*c);
+        }
+    }
+
+    result
+}
+
+/// What to do with digits at the start of an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum LeadingDigit {
+    /// put the digit prefix in front: `_3d_printer`
+    Prefix,
+    /// spell the digits out: `three_d_printer`
+    Spell,
+    /// drop the digits: `d_printer`
+    Drop,
+}
+
+pub struct Options {
+    pub leading_digit: LeadingDigit,
+    pub prefix: String,
+}
+
+const DIGITS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl Options {
+    /// Splits a label into the words of a valid identifier. Apostrophes inside
+    /// words are dropped, every other character that cannot be part of an
+    /// identifier separates words, and digits stay with the letters right
+    /// after them, so `3D` is a single word.
+    pub fn words(&self, text: &str) -> Vec<String> {
+        let text = drop_apostrophes(text);
+        let mut words: Vec<String> = Vec::new();
+        let mut end = 0;
+
+        for word in wcase::words(&text) {
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            let after_digits = start == end
+                && words
+                    .last()
+                    .is_some_and(|last| last.bytes().all(|b| b.is_ascii_digit()));
+
+            match words.last_mut() {
+                Some(last) if after_digits => last.push_str(word),
+                _ => words.push(word.to_string()),
+            }
+
+            end = start + word.len();
+        }
+
+        while let Some(first) = words.first() {
+            let digits = first.len() - first.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+            if digits == 0 || self.leading_digit == LeadingDigit::Prefix {
+                break;
+            }
+
+            let rest = first[digits..].to_string();
+            let spelled: Vec<String> = match self.leading_digit {
+                LeadingDigit::Spell => first[..digits]
+                    .bytes()
+                    .map(|digit| DIGITS[(digit - b'0') as usize].to_string())
+                    .collect(),
+                _ => vec![],
+            };
+
+            words.splice(
+                0..1,
+                spelled
+                    .into_iter()
+                    .chain(Some(rest).filter(|r| !r.is_empty())),
+            );
+        }
+
+        words
+    }
+
+    /// Puts the prefix in front of an identifier that starts with a digit.
+    pub fn fix_start(&self, identifier: String) -> String {
+        if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+            return format!("{}{}", self.prefix, identifier);
+        }
+
+        identifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{join_words, Case};
+
+    fn sanitize(text: &str, to: Case, leading_digit: LeadingDigit) -> String {
+        let options = Options {
+            leading_digit,
+            prefix: "_".to_string(),
+        };
+
+        options.fix_start(join_words(&options.words(text), to))
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let label = "3D Printer's Queue! 🖨️";
+
+        assert_eq!(
+            sanitize(label, Case::Snake, LeadingDigit::Prefix),
+            "_3d_printers_queue"
+        );
+        assert_eq!(
+            sanitize(label, Case::Pascal, LeadingDigit::Spell),
+            "ThreeDPrintersQueue"
+        );
+        assert_eq!(
+            sanitize(label, Case::Camel, LeadingDigit::Drop),
+            "dPrintersQueue"
+        );
+        assert_eq!(
+            sanitize("42 ways", Case::Kebab, LeadingDigit::Spell),
+            "four-two-ways"
+        );
+        assert_eq!(
+            sanitize("'quoted' 'n' rock", Case::Snake, LeadingDigit::Prefix),
+            "quoted_n_rock"
+        );
+        assert_eq!(
+            sanitize("3 D Printer", Case::Snake, LeadingDigit::Prefix),
+            "_3_d_printer"
+        );
+        assert_eq!(
+            sanitize("3D Printer", Case::Snake, LeadingDigit::Spell),
+            "three_d_printer"
+        );
+        assert_eq!(
+            sanitize("3D Printer", Case::Snake, LeadingDigit::Drop),
+            "d_printer"
+        );
+        assert_eq!(sanitize("!!!", Case::Snake, LeadingDigit::Drop), "");
+    }
+}
@@ -0,0 +1,101 @@
+/// One based line and column of a byte offset.
+pub fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Character cursor shared by the key scanners and the source lexers.
+pub struct Scanner<'a> {
+    pub src: &'a str,
+    pub pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Scanner { src, pos: 0 }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    pub fn peek_nth(&self, n: usize) -> Option<char> {
+        self.src[self.pos..].chars().nth(n)
+    }
+
+    pub fn peek_back(&self) -> Option<char> {
+        self.src[..self.pos].chars().next_back()
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    pub fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.bump();
+            return true;
+        }
+
+        false
+    }
+
+    pub fn eat_str(&mut self, expected: &str) -> bool {
+        if self.src[self.pos..].starts_with(expected) {
+            self.pos += expected.len();
+            return true;
+        }
+
+        false
+    }
+
+    /// Skips spaces and tabs, returning how many were skipped.
+    pub fn skip_spaces(&mut self) -> usize {
+        let mut count = 0;
+
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.bump();
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Skips a comment, stopping before the line break.
+    pub fn skip_comment(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.bump();
+        }
+    }
+
+    /// Skips the rest of the line including the line break.
+    pub fn skip_line(&mut self) {
+        self.skip_comment();
+        self.bump();
+    }
+
+    /// Skips a single line quoted string whose opening quote was already
+    /// consumed, honoring backslash escapes in double quoted strings.
+    pub fn skip_quoted(&mut self, quote: char) -> Result<(), String> {
+        loop {
+            match self.bump() {
+                Some('\\') if quote == '"' => {
+                    self.bump();
+                }
+                Some(c) if c == quote => return Ok(()),
+                Some('\n') | None => return Err(self.error("unterminated string")),
+                Some(_) => (),
+            }
+        }
+    }
+
+    pub fn error(&self, msg: &str) -> String {
+        let (line, column) = position(self.src, self.pos);
+        format!("Invalid input at {}:{}: {}", line, column, msg)
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+/// Common english and programming words, the most frequent first.
+const WORDS: &str = include_str!("words.txt");
+
+/// Splits are ambiguous when the runner up is at most this much less likely,
+/// in natural log units.
+const AMBIGUITY: f64 = 1.0;
+
+/// Finds the words of a flat or upper case word, as in `usernamefield`,
+/// picking the most likely split by word frequency. Words that cannot be
+/// split into known words and numbers are kept whole.
+pub struct Segmenter {
+    costs: HashMap<String, f64>,
+}
+
+/// The best split of a word, and another one when it is about as likely.
+#[derive(Debug, PartialEq)]
+pub struct Segmentation {
+    pub words: Vec<String>,
+    pub alternative: Option<Vec<String>>,
+}
+
+impl Segmenter {
+    /// User words are ranked before the embedded ones, in their given order.
+    pub fn new(user_words: &[String]) -> Segmenter {
+        let mut ranked: Vec<String> = user_words.iter().map(|word| word.to_lowercase()).collect();
+        ranked.extend(WORDS.lines().map(String::from));
+
+        let log_n = (ranked.len() as f64).ln();
+        let mut costs = HashMap::new();
+
+        // Zipf's law: the probability of a word is about 1 / (rank * ln n).
+        for (rank, word) in ranked.iter().enumerate() {
+            let cost = ((rank + 1) as f64 * log_n).ln();
+            costs.entry(word.clone()).or_insert(cost);
+        }
+
+        Segmenter { costs }
+    }
+
+    fn cost(&self, word: &str) -> Option<f64> {
+        match self.costs.get(word) {
+            Some(cost) => Some(*cost),
+            None if word.bytes().all(|b| b.is_ascii_digit()) => Some(0.0),
+            None => None,
+        }
+    }
+
+    pub fn segment(&self, word: &str) -> Segmentation {
+        let text = word.to_lowercase();
+
+        let whole = Segmentation {
+            words: vec![word.to_string()],
+            alternative: None,
+        };
+
+        if !text.is_ascii() || text.is_empty() {
+            return whole;
+        }
+
+        // The two cheapest splits of every prefix, as (cost, start of the
+        // last word, which of the two splits of that start it extends).
+        let mut best: Vec<Vec<(f64, usize, usize)>> = vec![vec![(0.0, 0, 0)]];
+
+        for end in 1..=text.len() {
+            let mut candidates = Vec::new();
+
+            for start in 0..end {
+                let cost = match self.cost(&text[start..end]) {
+                    Some(cost) => cost,
+                    None => continue,
+                };
+
+                for (k, (previous, _, _)) in best[start].iter().enumerate() {
+                    candidates.push((previous + cost, start, k));
+                }
+            }
+
+            candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+            candidates.truncate(2);
+            best.push(candidates);
+        }
+
+        if best[text.len()].is_empty() {
+            return whole;
+        }
+
+        let words = self.backtrack(&text, &best, 0);
+        let alternative = match best[text.len()].get(1) {
+            Some((cost, _, _)) if cost - best[text.len()][0].0 <= AMBIGUITY => {
+                Some(self.backtrack(&text, &best, 1))
+            }
+            _ => None,
+        };
+
+        Segmentation { words, alternative }
+    }
+
+    fn backtrack(&self, text: &str, best: &[Vec<(f64, usize, usize)>], k: usize) -> Vec<String> {
+        let mut words = Vec::new();
+        let (mut end, mut k) = (text.len(), k);
+
+        while end > 0 {
+            let (_, start, previous) = best[end][k];
+            words.push(text[start..end].to_string());
+            end = start;
+            k = previous;
+        }
+
+        words.reverse();
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(segmenter: &Segmenter, word: &str) -> String {
+        segmenter.segment(word).words.join(" ")
+    }
+
+    #[test]
+    fn test_segment() {
+        let segmenter = Segmenter::new(&[]);
+
+        assert_eq!(words(&segmenter, "usernamefield"), "user name field");
+        assert_eq!(words(&segmenter, "HELLOWORLD"), "hello world");
+        assert_eq!(words(&segmenter, "maxretrycount"), "max retry count");
+        assert_eq!(words(&segmenter, "createdat"), "created at");
+        assert_eq!(words(&segmenter, "page2size"), "page 2 size");
+        assert_eq!(words(&segmenter, "therapist"), "therapist");
+        assert_eq!(words(&segmenter, "xyzfield"), "xyzfield");
+    }
+
+    #[test]
+    fn test_user_words() {
+        let segmenter = Segmenter::new(&["wcase".to_string()]);
+
+        assert_eq!(words(&segmenter, "wcaseconfig"), "wcase config");
+    }
+
+    #[test]
+    fn test_ambiguity() {
+        let segmenter = Segmenter::new(&[]);
+        let segmentation = segmenter.segment("usernamefield");
+
+        assert_eq!(segmentation.alternative, None);
+
+        let segmentation = segmenter.segment("nowhere");
+
+        assert_eq!(segmentation.words, vec!["no", "where"]);
+        assert_eq!(
+            segmentation.alternative,
+            Some(vec!["now".to_string(), "here".to_string()])
+        );
+    }
+}
@@ -0,0 +1,879 @@
+//! Renames the fields of any `Serialize` or `Deserialize` type to a case
+//! chosen at runtime, so one struct can be read and written as `user_id` for
+//! one client and `userId` for another.
+//!
+//! ```ignore
+//! let camel = Target::Case(Case::Camel);
+//! let json = serde_json::to_string(&Rename::new(&user, &camel))?;
+//! let user: User = wcase::serde::deserialize(&mut serde_json::Deserializer::from_str(&json), &camel)?;
+//! ```
+//!
+//! Only the fields of structs and struct variants are renamed: the keys of
+//! maps are data, their case is kept both ways. Renamed fields are leaked, once
+//! per distinct name, as serde needs them to live as long as the program.
+
+use crate::custom::Target;
+use crate::split_words;
+use ::serde::de::{self, DeserializeSeed, IntoDeserializer};
+use ::serde::ser::{self, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Mutex;
+
+/// Field names are `&'static str` in serde, so renamed ones are leaked. Each
+/// distinct name is leaked once and reused after, which bounds the memory to
+/// the fields of the program times the targets it renames them to. Building
+/// targets from untrusted input, like a case defined by each request, can
+/// grow it without bound.
+fn intern(name: String) -> &'static str {
+    static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(name) = names.get(name.as_str()) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(name);
+    name
+}
+
+fn rename(field: &'static str, target: &Target) -> &'static str {
+    let renamed = target.join(&split_words(field));
+
+    if renamed.is_empty() || renamed == field {
+        return field;
+    }
+
+    intern(renamed)
+}
+
+/// Renamed lists of fields, leaked once each like their names.
+fn rename_all(fields: &'static [&'static str], target: &Target) -> &'static [&'static str] {
+    type Lists = BTreeMap<Vec<&'static str>, &'static [&'static str]>;
+    static LISTS: Mutex<Lists> = Mutex::new(BTreeMap::new());
+
+    let renamed: Vec<&'static str> = fields.iter().map(|field| rename(field, target)).collect();
+    let mut lists = LISTS.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(list) = lists.get(&renamed) {
+        return list;
+    }
+
+    let list: &'static [&'static str] = Box::leak(renamed.clone().into_boxed_slice());
+    lists.insert(renamed, list);
+    list
+}
+
+/// A value serialized with its fields in the `target` case.
+pub struct Rename<'a, T: ?Sized> {
+    value: &'a T,
+    target: &'a Target,
+}
+
+impl<'a, T: ?Sized> Rename<'a, T> {
+    pub fn new(value: &'a T, target: &'a Target) -> Rename<'a, T> {
+        Rename { value, target }
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for Rename<'_, T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value
+            .serialize(Serializer::new(serializer, self.target))
+    }
+}
+
+/// Deserializes a value whose fields are in the `target` case.
+pub fn deserialize<'de, T, D>(deserializer: D, target: &Target) -> Result<T, D::Error>
+where
+    T: de::Deserialize<'de>,
+    D: de::Deserializer<'de>,
+{
+    T::deserialize(Deserializer::new(deserializer, target))
+}
+
+/// Wraps a serializer, renaming fields on their way out.
+pub struct Serializer<'a, S> {
+    inner: S,
+    target: &'a Target,
+}
+
+impl<'a, S> Serializer<'a, S> {
+    pub fn new(inner: S, target: &'a Target) -> Serializer<'a, S> {
+        Serializer { inner, target }
+    }
+}
+
+/// The serializer of a sequence, map or struct, renaming what it contains.
+pub struct Compound<'a, C> {
+    inner: C,
+    target: &'a Target,
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($($arg:ident: $type:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $($arg: $type),*) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<'a, S: ser::Serializer> ser::Serializer for Serializer<'a, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<'a, S::SerializeSeq>;
+    type SerializeTuple = Compound<'a, S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<'a, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<'a, S::SerializeTupleVariant>;
+    type SerializeMap = Compound<'a, S::SerializeMap>;
+    type SerializeStruct = Compound<'a, S::SerializeStruct>;
+    type SerializeStructVariant = Compound<'a, S::SerializeStructVariant>;
+
+    forward_serialize!(
+        serialize_bool(v: bool),
+        serialize_i8(v: i8),
+        serialize_i16(v: i16),
+        serialize_i32(v: i32),
+        serialize_i64(v: i64),
+        serialize_i128(v: i128),
+        serialize_u8(v: u8),
+        serialize_u16(v: u16),
+        serialize_u32(v: u32),
+        serialize_u64(v: u64),
+        serialize_u128(v: u128),
+        serialize_f32(v: f32),
+        serialize_f64(v: f64),
+        serialize_char(v: char),
+        serialize_str(v: &str),
+        serialize_bytes(v: &[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(name: &'static str),
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str),
+    );
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_some(&Rename::new(value, self.target))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = Rename::new(value, self.target);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        let value = Rename::new(value, self.target);
+        self.inner
+            .serialize_newtype_variant(name, index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        let inner = self.inner.serialize_seq(len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        let inner = self.inner.serialize_tuple(len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        let inner = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        let inner = self
+            .inner
+            .serialize_tuple_variant(name, index, variant, len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        let inner = self.inner.serialize_struct(name, len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        let inner = self
+            .inner
+            .serialize_struct_variant(name, index, variant, len)?;
+        Ok(Compound {
+            inner,
+            target: self.target,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! serialize_elements {
+    ($($trait:ident::$method:ident),* $(,)?) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for Compound<'_, C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+                    self.inner.$method(&Rename::new(value, self.target))
+                }
+
+                fn end(self) -> Result<C::Ok, C::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+serialize_elements!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+);
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), C::Error> {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_value(&Rename::new(value, self.target))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+macro_rules! serialize_fields {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<C: ser::$trait> ser::$trait for Compound<'_, C> {
+                type Ok = C::Ok;
+                type Error = C::Error;
+
+                fn serialize_field<T: ?Sized + Serialize>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), C::Error> {
+                    let key = rename(key, self.target);
+                    self.inner.serialize_field(key, &Rename::new(value, self.target))
+                }
+
+                fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+                    self.inner.skip_field(rename(key, self.target))
+                }
+
+                fn end(self) -> Result<C::Ok, C::Error> {
+                    self.inner.end()
+                }
+            }
+        )*
+    };
+}
+
+serialize_fields!(SerializeStruct, SerializeStructVariant);
+
+/// Wraps a deserializer, renaming fields back to their rust names.
+pub struct Deserializer<'a, D> {
+    inner: D,
+    target: &'a Target,
+}
+
+impl<'a, D> Deserializer<'a, D> {
+    pub fn new(inner: D, target: &'a Target) -> Deserializer<'a, D> {
+        Deserializer { inner, target }
+    }
+
+    fn visitor<V>(&self, inner: V, fields: Option<&'static [&'static str]>) -> Visitor<'a, V> {
+        Visitor {
+            inner,
+            target: self.target,
+            fields,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $type:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(
+                self,
+                $($arg: $type,)*
+                visitor: V,
+            ) -> Result<V::Value, D::Error> {
+                let visitor = self.visitor(visitor, None);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: de::Deserializer<'de>> de::Deserializer<'de> for Deserializer<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.visitor(visitor, Some(fields));
+        let renamed = rename_all(fields, self.target);
+        self.inner.deserialize_struct(name, renamed, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Wraps a visitor, so whatever it visits is renamed too. `fields` are the
+/// rust names of a struct, whose keys are mapped back to them.
+struct Visitor<'a, V> {
+    inner: V,
+    target: &'a Target,
+    fields: Option<&'static [&'static str]>,
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($($arg:ident: $type:ty),*)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, $($arg: $type),*) -> Result<V::Value, E> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<'de, V: de::Visitor<'de>> de::Visitor<'de> for Visitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit!(
+        visit_bool(v: bool),
+        visit_i8(v: i8),
+        visit_i16(v: i16),
+        visit_i32(v: i32),
+        visit_i64(v: i64),
+        visit_i128(v: i128),
+        visit_u8(v: u8),
+        visit_u16(v: u16),
+        visit_u32(v: u32),
+        visit_u64(v: u64),
+        visit_u128(v: u128),
+        visit_f32(v: f32),
+        visit_f64(v: f64),
+        visit_char(v: char),
+        visit_str(v: &str),
+        visit_borrowed_str(v: &'de str),
+        visit_string(v: String),
+        visit_bytes(v: &[u8]),
+        visit_borrowed_bytes(v: &'de [u8]),
+        visit_byte_buf(v: Vec<u8>),
+        visit_none(),
+        visit_unit(),
+    );
+
+    fn visit_some<D: de::Deserializer<'de>>(self, d: D) -> Result<V::Value, D::Error> {
+        self.inner.visit_some(Deserializer::new(d, self.target))
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(self, d: D) -> Result<V::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(Deserializer::new(d, self.target))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_seq(Access {
+            inner: seq,
+            target: self.target,
+            fields: None,
+        })
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_map(Access {
+            inner: map,
+            target: self.target,
+            fields: self.fields,
+        })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_enum(Access {
+            inner: data,
+            target: self.target,
+            fields: None,
+        })
+    }
+}
+
+/// Deserializes a value through a renaming deserializer.
+struct Seed<'a, S> {
+    inner: S,
+    target: &'a Target,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Seed<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, d: D) -> Result<S::Value, D::Error> {
+        self.inner.deserialize(Deserializer::new(d, self.target))
+    }
+}
+
+/// Deserializes a key of a struct, given to the inner seed by its rust name.
+struct Key<'a, S> {
+    inner: S,
+    target: &'a Target,
+    fields: &'static [&'static str],
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Key<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, d: D) -> Result<S::Value, D::Error> {
+        d.deserialize_identifier(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> de::Visitor<'de> for Key<'_, S> {
+    type Value = S::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a field name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<S::Value, E> {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| rename(field, self.target) == v)
+            .copied();
+
+        match field {
+            Some(field) => self.inner.deserialize(field.into_deserializer()),
+            None => self.inner.deserialize(v.into_deserializer()),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<S::Value, E> {
+        match std::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => self.inner.deserialize(v.into_deserializer()),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<S::Value, E> {
+        self.inner.deserialize(v.into_deserializer())
+    }
+}
+
+/// Wraps the access to the elements of a sequence, map or enum.
+struct Access<'a, A> {
+    inner: A,
+    target: &'a Target,
+    fields: Option<&'static [&'static str]>,
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for Access<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        let seed = Seed {
+            inner: seed,
+            target: self.target,
+        };
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for Access<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, A::Error> {
+        match self.fields {
+            Some(fields) => self.inner.next_key_seed(Key {
+                inner: seed,
+                target: self.target,
+                fields,
+            }),
+            None => self.inner.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, A::Error> {
+        let seed = Seed {
+            inner: seed,
+            target: self.target,
+        };
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for Access<'a, A> {
+    type Error = A::Error;
+    type Variant = Access<'a, A::Variant>;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), A::Error> {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        let variant = Access {
+            inner: variant,
+            target: self.target,
+            fields: None,
+        };
+
+        Ok((value, variant))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for Access<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<S::Value, A::Error> {
+        let seed = Seed {
+            inner: seed,
+            target: self.target,
+        };
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = Visitor {
+            inner: visitor,
+            target: self.target,
+            fields: None,
+        };
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = Visitor {
+            inner: visitor,
+            target: self.target,
+            fields: Some(fields),
+        };
+        self.inner
+            .struct_variant(rename_all(fields, self.target), visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::CustomCase;
+    use crate::Case;
+    use ::serde::de::Deserialize;
+    use ::serde::ser::SerializeStruct;
+
+    #[derive(Debug, PartialEq)]
+    struct User {
+        user_id: u32,
+        display_name: Option<String>,
+        tags: BTreeMap<String, String>,
+    }
+
+    impl Serialize for User {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut user = serializer.serialize_struct("User", 3)?;
+            user.serialize_field("user_id", &self.user_id)?;
+            user.serialize_field("display_name", &self.display_name)?;
+            user.serialize_field("tags", &self.tags)?;
+            user.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for User {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<User, D::Error> {
+            struct UserVisitor;
+
+            impl<'de> de::Visitor<'de> for UserVisitor {
+                type Value = User;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "a user")
+                }
+
+                fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<User, A::Error> {
+                    let mut user = User {
+                        user_id: 0,
+                        display_name: None,
+                        tags: BTreeMap::new(),
+                    };
+
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "user_id" => user.user_id = map.next_value()?,
+                            "display_name" => user.display_name = map.next_value()?,
+                            "tags" => user.tags = map.next_value()?,
+                            _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                        }
+                    }
+
+                    Ok(user)
+                }
+            }
+
+            const FIELDS: &[&str] = &["user_id", "display_name", "tags"];
+            deserializer.deserialize_struct("User", FIELDS, UserVisitor)
+        }
+    }
+
+    fn user() -> User {
+        User {
+            user_id: 7,
+            display_name: Some("Ann".to_string()),
+            tags: BTreeMap::from([("first_seen".to_string(), "2024".to_string())]),
+        }
+    }
+
+    fn to_json(user: &[User], target: &Target) -> String {
+        serde_json::to_string(&Rename::new(user, target)).unwrap()
+    }
+
+    fn from_json(json: &str, target: &Target) -> Result<Vec<User>, serde_json::Error> {
+        deserialize(&mut serde_json::Deserializer::from_str(json), target)
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            to_json(&[user()], &Target::Case(Case::Camel)),
+            r#"[{"userId":7,"displayName":"Ann","tags":{"first_seen":"2024"}}]"#
+        );
+        assert_eq!(
+            to_json(&[user()], &Target::Case(Case::Snake)),
+            r#"[{"user_id":7,"display_name":"Ann","tags":{"first_seen":"2024"}}]"#
+        );
+
+        let member: CustomCase = "member=m_{lower}{capital}".parse().unwrap();
+        assert_eq!(
+            to_json(&[user()], &Target::Custom(member)),
+            r#"[{"m_userId":7,"m_displayName":"Ann","m_tags":{"first_seen":"2024"}}]"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let train = Target::Case(Case::Train);
+        let json = to_json(&[user()], &train);
+
+        assert_eq!(
+            json,
+            r#"[{"USER-ID":7,"DISPLAY-NAME":"Ann","TAGS":{"first_seen":"2024"}}]"#
+        );
+        assert_eq!(from_json(&json, &train).unwrap(), vec![user()]);
+        assert!(from_json(&json, &Target::Case(Case::Camel)).is_err());
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Order {
+        order_id: u32,
+        shipping_address: Address,
+        gift_note: Option<String>,
+        line_items: Vec<LineItem>,
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Address {
+        street_name: String,
+        postal_code: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    enum LineItem {
+        Product { product_id: u32, unit_count: u8 },
+        GiftCard { card_value: u32 },
+    }
+
+    fn order() -> Order {
+        Order {
+            order_id: 1,
+            shipping_address: Address {
+                street_name: "Main Street".to_string(),
+                postal_code: None,
+            },
+            gift_note: Some("Enjoy".to_string()),
+            line_items: vec![
+                LineItem::Product {
+                    product_id: 5,
+                    unit_count: 2,
+                },
+                LineItem::GiftCard { card_value: 20 },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_derive_round_trip() {
+        let camel = Target::Case(Case::Camel);
+        let json = serde_json::to_string(&Rename::new(&order(), &camel)).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"orderId":1,"shippingAddress":{"streetName":"Main Street","postalCode":null},"giftNote":"Enjoy","lineItems":[{"Product":{"productId":5,"unitCount":2}},{"GiftCard":{"cardValue":20}}]}"#
+        );
+
+        let order_of = |json: &str, target: &Target| -> Result<Order, serde_json::Error> {
+            deserialize(&mut serde_json::Deserializer::from_str(json), target)
+        };
+
+        assert_eq!(order_of(&json, &camel).unwrap(), order());
+        assert!(order_of(&json, &Target::Case(Case::Snake)).is_err());
+
+        // missing options are none, as without renaming
+        let json =
+            r#"{"ORDER_ID":2,"SHIPPING_ADDRESS":{"STREET_NAME":"Elm Road"},"LINE_ITEMS":[]}"#;
+        assert_eq!(
+            order_of(json, &Target::Case(Case::AllCaps)).unwrap(),
+            Order {
+                order_id: 2,
+                shipping_address: Address {
+                    street_name: "Elm Road".to_string(),
+                    postal_code: None,
+                },
+                gift_note: None,
+                line_items: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_intern() {
+        let first = intern("user_name".to_string());
+        let second = intern("user_name".to_string());
+
+        assert!(std::ptr::eq(first, second));
+    }
+}
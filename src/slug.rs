@@ -0,0 +1,168 @@
+use crate::ascii::transliterate;
+use crate::sanitize::drop_apostrophes;
+use crate::{join_words, Case};
+use std::collections::HashSet;
+
+#[rustfmt::skip]
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "in", "into", "is",
+    "it", "of", "on", "or", "the", "to", "with",
+];
+
+pub struct Options {
+    pub max_len: Option<usize>,
+    pub stop_words: bool,
+    pub unique: bool,
+}
+
+/// Makes url slugs, remembering the ones already given out when they must be
+/// unique.
+pub struct Slugger {
+    options: Options,
+    seen: HashSet<String>,
+}
+
+impl Slugger {
+    pub fn new(options: Options) -> Slugger {
+        Slugger {
+            options,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Turns a title into a lowercase ascii slug. A slug that was already
+    /// given out gets a `-2`, `-3`, ... suffix, still within the max length.
+    pub fn slug(&mut self, text: &str) -> String {
+        let words = self.words(text);
+        let mut slug = fit(&words, self.options.max_len, "");
+
+        if !self.options.unique || slug.is_empty() {
+            return slug;
+        }
+
+        let mut n = 2;
+
+        while self.seen.contains(&slug) {
+            slug = fit(&words, self.options.max_len, &format!("-{}", n));
+            n += 1;
+        }
+
+        self.seen.insert(slug.clone());
+        slug
+    }
+
+    fn words(&self, text: &str) -> Vec<String> {
+        let text = transliterate(&drop_apostrophes(text)).to_lowercase();
+
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(String::from)
+            .collect();
+
+        if !self.options.stop_words {
+            return words;
+        }
+
+        let kept: Vec<String> = words
+            .iter()
+            .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+            .cloned()
+            .collect();
+
+        // A title made only of stop words keeps them all.
+        if kept.is_empty() {
+            return words;
+        }
+
+        kept
+    }
+}
+
+/// Joins as many words as fit in the max length along with the suffix,
+/// cutting the first word only when it does not fit on its own.
+fn fit(words: &[String], max_len: Option<usize>, suffix: &str) -> String {
+    let max_len = match max_len {
+        Some(max_len) => max_len.saturating_sub(suffix.len()),
+        None => return join_words(words, Case::Kebab) + suffix,
+    };
+
+    let mut len = 0;
+    let mut count = 0;
+
+    for word in words {
+        let added = if count == 0 {
+            word.len()
+        } else {
+            word.len() + 1
+        };
+
+        if len + added > max_len {
+            break;
+        }
+
+        len += added;
+        count += 1;
+    }
+
+    let slug = match (count, words.first()) {
+        (0, Some(first)) => first[..max_len.min(first.len())].to_string(),
+        _ => join_words(&words[..count], Case::Kebab),
+    };
+
+    if slug.is_empty() {
+        return suffix.trim_start_matches('-').to_string();
+    }
+
+    slug + suffix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slugger(max_len: Option<usize>, stop_words: bool, unique: bool) -> Slugger {
+        Slugger::new(Options {
+            max_len,
+            stop_words,
+            unique,
+        })
+    }
+
+    #[test]
+    fn test_slug() {
+        let mut slugger = slugger(None, false, false);
+
+        assert_eq!(
+            slugger.slug("  Hello,   World! It's Café Time "),
+            "hello-world-its-cafe-time"
+        );
+        assert_eq!(slugger.slug("iPhone 15 Pro"), "iphone-15-pro");
+        assert_eq!(slugger.slug("!!!"), "");
+    }
+
+    #[test]
+    fn test_slug_stop_words_and_max_len() {
+        let mut slugger = slugger(Some(20), true, false);
+
+        assert_eq!(
+            slugger.slug("The Art of Computer Programming"),
+            "art-computer"
+        );
+        assert_eq!(slugger.slug("The And Of"), "the-and-of");
+        assert_eq!(
+            slugger.slug("Pneumonoultramicroscopicsilicovolcanoconiosis"),
+            "pneumonoultramicrosc"
+        );
+    }
+
+    #[test]
+    fn test_unique_slugs() {
+        let mut slugger = slugger(Some(12), false, true);
+
+        assert_eq!(slugger.slug("Release notes"), "release");
+        assert_eq!(slugger.slug("Release Notes!"), "release-2");
+        assert_eq!(slugger.slug("release"), "release-3");
+        assert_eq!(slugger.slug("Notes"), "notes");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::ascii::transliterate;
+use crate::custom::Target;
+use std::collections::HashMap;
+
+pub struct Options {
+    pub to: Target,
+    pub delimiter: u8,
+    pub column: Option<String>,
+    pub ascii: bool,
+}
+
+impl Options {
+    fn source(&self, text: &str) -> String {
+        if self.ascii {
+            return transliterate(text);
+        }
+
+        text.to_string()
+    }
+}
+
+/// Two or more columns whose headers became the same after the conversion.
+#[derive(Debug, PartialEq)]
+pub struct Duplicate {
+    pub header: String,
+    pub columns: Vec<usize>,
+}
+
+/// Converts the header row, or the values of `options.column` when given.
+pub fn transform(input: &str, options: &Options) -> Result<(String, Vec<Duplicate>), String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(options.delimiter)
+        .from_reader(input.as_bytes());
+
+    let mut writer = csv::WriterBuilder::new()
+        .flexible(true)
+        .delimiter(options.delimiter)
+        .from_writer(Vec::new());
+
+    let mut records = reader.records();
+    let mut duplicates = Vec::new();
+
+    let header = match records.next() {
+        Some(header) => header.map_err(|e| format!("Invalid csv: {}", e))?,
+        None => return Ok((String::new(), duplicates)),
+    };
+
+    let header: Vec<String> = header.iter().map(String::from).collect();
+
+    let column = match &options.column {
+        Some(name) => match header.iter().position(|h| h == name) {
+            Some(index) => Some(index),
+            None => return Err(format!("Column \"{}\" not found", name)),
+        },
+        None => None,
+    };
+
+    if column.is_some() {
+        write(&mut writer, &header)?;
+    } else {
+        let source: Vec<String> = header.iter().map(|h| options.source(h)).collect();
+        let (converted, found) = convert_headers(&source, &options.to);
+        write(&mut writer, &converted)?;
+        duplicates = found;
+    }
+
+    for record in records {
+        let mut record: Vec<String> = record
+            .map_err(|e| format!("Invalid csv: {}", e))?
+            .iter()
+            .map(String::from)
+            .collect();
+
+        if let Some(value) = column.and_then(|index| record.get_mut(index)) {
+            *value = options.to.convert_lenient(&options.source(value));
+        }
+
+        write(&mut writer, &record)?;
+    }
+
+    let output = writer
+        .into_inner()
+        .map_err(|e| format!("Could not write csv: {}", e))?;
+
+    Ok((String::from_utf8(output).unwrap(), duplicates))
+}
+
+fn write(writer: &mut csv::Writer<Vec<u8>>, record: &[String]) -> Result<(), String> {
+    writer
+        .write_record(record)
+        .map_err(|e| format!("Could not write csv: {}", e))
+}
+
+/// Converts every header, reporting the ones that collide. Columns are one
+/// based, as spreadsheets count them.
+pub fn convert_headers(headers: &[String], to: &Target) -> (Vec<String>, Vec<Duplicate>) {
+    let converted: Vec<String> = headers.iter().map(|h| to.convert_lenient(h)).collect();
+
+    let mut columns: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, header) in converted.iter().enumerate() {
+        columns.entry(header).or_default().push(i + 1);
+    }
+
+    let mut duplicates: Vec<Duplicate> = columns
+        .into_iter()
+        .filter(|(_, columns)| columns.len() > 1)
+        .map(|(header, columns)| Duplicate {
+            header: header.to_string(),
+            columns,
+        })
+        .collect();
+
+    duplicates.sort_by_key(|duplicate| duplicate.columns[0]);
+
+    (converted, duplicates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Case;
+
+    fn options(to: Case) -> Options {
+        Options {
+            to: Target::Case(to),
+            delimiter: b',',
+            column: None,
+            ascii: false,
+        }
+    }
+
+    #[test]
+    fn test_transform_header() {
+        let input = "First Name,E-mail Address,ZIP code\nJohn Doe,john@doe.com,1234\n";
+        let expected = "first_name,e_mail_address,zip_code\nJohn Doe,john@doe.com,1234\n";
+
+        let (output, duplicates) = transform(input, &options(Case::Snake)).unwrap();
+
+        assert_eq!(output, expected);
+        assert!(duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_transform_header_ascii() {
+        let mut options = options(Case::Snake);
+        options.ascii = true;
+
+        let (output, _) = transform("Größe,Café Crème\n", &options).unwrap();
+
+        assert_eq!(output, "groesse,cafe_creme\n");
+    }
+
+    #[test]
+    fn test_transform_column() {
+        let input = "id\tStatus\n1\tIn Progress\n2\tDONE\n";
+        let expected = "id\tStatus\n1\tIN_PROGRESS\n2\tDONE\n";
+
+        let mut options = options(Case::AllCaps);
+        options.delimiter = b'\t';
+        options.column = Some("Status".to_string());
+
+        assert_eq!(transform(input, &options).unwrap().0, expected);
+
+        options.column = Some("missing".to_string());
+        assert!(transform(input, &options).is_err());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let headers: Vec<String> = ["User Name", "id", "user-name", "userName"]
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+
+        let (converted, duplicates) = convert_headers(&headers, &Target::Case(Case::Snake));
+
+        assert_eq!(converted, vec!["user_name", "id", "user_name", "user_name"]);
+        assert_eq!(
+            duplicates,
+            vec![Duplicate {
+                header: "user_name".to_string(),
+                columns: vec![1, 3, 4],
+            }]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Exports behind the JavaScript API of `wasm/wcase.js`. Strings cross the
+//! boundary as a pointer and a length of utf-8 bytes in the linear memory,
+//! cases as their number in `CASES`.
+
+use crate::{convert, get_case, Case};
+use alloc::vec::Vec;
+use core::{slice, str};
+
+/// Cases by their number in JavaScript.
+pub const CASES: [Case; 12] = [
+    Case::Flat,
+    Case::Upper,
+    Case::Camel,
+    Case::Pascal,
+    Case::Snake,
+    Case::AllCaps,
+    Case::Kebab,
+    Case::Train,
+    Case::Spaced,
+    Case::Title,
+    Case::HttpHeader,
+    Case::None,
+];
+
+/// The input has no case to convert from.
+pub const INVALID_INPUT: isize = -1;
+/// The number of the case to convert to is unknown.
+pub const UNKNOWN_CASE: isize = -2;
+
+fn number(case: Case) -> u32 {
+    CASES.iter().position(|c| *c == case).unwrap() as u32
+}
+
+/// # Safety
+///
+/// `ptr` and `len` must be a string given to JavaScript by `wcase_wasm_alloc`.
+unsafe fn text<'a>(ptr: *const u8, len: usize) -> Option<&'a str> {
+    str::from_utf8(slice::from_raw_parts(ptr, len)).ok()
+}
+
+/// Allocates `capacity` bytes for JavaScript to write a string to.
+#[no_mangle]
+pub extern "C" fn wcase_wasm_alloc(capacity: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(capacity);
+    let ptr = buffer.as_mut_ptr();
+    core::mem::forget(buffer);
+    ptr
+}
+
+/// # Safety
+///
+/// `ptr` and `capacity` must come from the same call to `wcase_wasm_alloc`.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_wasm_free(ptr: *mut u8, capacity: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, capacity));
+}
+
+/// The number of the case of a string, the one of `Case::None` when it has
+/// none.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_wasm_detect(ptr: *const u8, len: usize) -> u32 {
+    match text(ptr, len) {
+        Some(text) => number(get_case(text)),
+        None => number(Case::None),
+    }
+}
+
+/// Converts a string like `wcase -w`, writing it to `out` when it fits in
+/// `capacity`. Returns its length, to try again with a larger buffer when it
+/// doesn't fit, or `INVALID_INPUT` or `UNKNOWN_CASE`.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` bytes and `out` to `capacity` writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_wasm_convert(
+    ptr: *const u8,
+    len: usize,
+    to: u32,
+    out: *mut u8,
+    capacity: usize,
+) -> isize {
+    let to = match CASES.get(to as usize) {
+        Some(Case::None) | None => return UNKNOWN_CASE,
+        Some(to) => *to,
+    };
+
+    let (text, case) = match text(ptr, len) {
+        Some(text) => (text, get_case(text)),
+        None => return INVALID_INPUT,
+    };
+
+    if case == Case::None {
+        return INVALID_INPUT;
+    }
+
+    let converted = convert(text, case, to);
+
+    if converted.len() <= capacity {
+        slice::from_raw_parts_mut(out, converted.len()).copy_from_slice(converted.as_bytes());
+    }
+
+    converted.len() as isize
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::convert_lenient;
+    use alloc::format;
+    use alloc::string::String;
+    use std::{env, fs};
+
+    fn name(case: Case) -> String {
+        convert_lenient(&format!("{}", case), Case::Pascal)
+    }
+
+    fn javascript() -> String {
+        let mut js = String::from("// Generated from the cases of wcase, do not edit.\n\n");
+        js.push_str("export const Case = Object.freeze({\n");
+
+        for (i, case) in CASES.iter().enumerate() {
+            js.push_str(&format!("  {}: {},\n", name(*case), i));
+        }
+
+        js.push_str("});\n");
+        js
+    }
+
+    fn typescript() -> String {
+        let mut ts = String::from("// Generated from the cases of wcase, do not edit.\n\n");
+        ts.push_str("export declare enum Case {\n");
+
+        for (i, case) in CASES.iter().enumerate() {
+            ts.push_str(&format!("  {} = {},\n", name(*case), i));
+        }
+
+        ts.push_str("}\n");
+        ts
+    }
+
+    /// Compares the generated files of `wasm/`, or writes them with
+    /// `WCASE_UPDATE=1`.
+    #[test]
+    fn test_generated_files_are_up_to_date() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("wasm");
+
+        for (file, expected) in [("case.js", javascript()), ("case.d.ts", typescript())] {
+            let path = dir.join(file);
+
+            if env::var_os("WCASE_UPDATE").is_some() {
+                fs::write(&path, &expected).unwrap();
+            }
+
+            let actual = fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                actual == expected,
+                "wasm/{} is out of date, run WCASE_UPDATE=1 cargo test --features wasm",
+                file
+            );
+        }
+    }
+
+    fn call(text: &str, to: Case) -> Result<String, isize> {
+        let mut out = alloc::vec![0u8; 4];
+
+        loop {
+            let number = number(to);
+            let written = unsafe {
+                wcase_wasm_convert(
+                    text.as_ptr(),
+                    text.len(),
+                    number,
+                    out.as_mut_ptr(),
+                    out.len(),
+                )
+            };
+
+            if written < 0 {
+                return Err(written);
+            }
+
+            if written as usize <= out.len() {
+                out.truncate(written as usize);
+                return Ok(String::from_utf8(out).unwrap());
+            }
+
+            out.resize(written as usize, 0);
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(call("helloWorld", Case::Snake).unwrap(), "hello_world");
+        assert_eq!(
+            call("hello_world", Case::HttpHeader).unwrap(),
+            "Hello-World"
+        );
+        assert_eq!(call("", Case::Pascal).unwrap(), "");
+        assert_eq!(call("_", Case::Camel).unwrap(), "");
+        assert_eq!(call("hello_World-x", Case::Snake), Err(INVALID_INPUT));
+        assert_eq!(call("hello", Case::None), Err(UNKNOWN_CASE));
+    }
+
+    #[test]
+    fn test_detect() {
+        let detect =
+            |text: &str| CASES[unsafe { wcase_wasm_detect(text.as_ptr(), text.len()) } as usize];
+
+        assert_eq!(detect("Hello-World"), Case::HttpHeader);
+        assert_eq!(detect("hello_World-x"), Case::None);
+    }
+
+    #[test]
+    fn test_alloc() {
+        let ptr = wcase_wasm_alloc(16);
+        unsafe { wcase_wasm_free(ptr, 16) };
+    }
+}
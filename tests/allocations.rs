@@ -0,0 +1,73 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Write};
+use wcase::{convert_into, words, Case};
+
+/// Counts the allocations of the current thread, as tests run side by side.
+struct Counter;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static COUNTER: Counter = Counter;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+/// A writer on the stack.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_words_do_not_allocate() {
+    let count = allocations(|| {
+        assert_eq!(words("parseHTTPResponse for the E-mail Address").count(), 8);
+    });
+
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_convert_into_does_not_allocate() {
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+
+    let count = allocations(|| {
+        convert_into("parseHTTPResponse", Case::HttpHeader, &mut buffer).unwrap();
+    });
+
+    assert_eq!(count, 0);
+    assert_eq!(&buffer.bytes[..buffer.len], b"Parse-Http-Response");
+}
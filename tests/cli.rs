@@ -0,0 +1,419 @@
+//! Runs the binary end to end, without the configuration files of the
+//! machine.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::{env, fs};
+
+fn run(args: &[&str], stdin: &str, dir: &Path) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wcase"))
+        .arg("--no-config")
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // usage errors exit before reading stdin, which then breaks the pipe
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+/// The stdout of a successful run.
+fn wcase(args: &[&str], stdin: &str) -> String {
+    let output = run(args, stdin, &env::temp_dir());
+    assert!(
+        output.status.success(),
+        "wcase {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The exit code and stderr of a failed run.
+fn wcase_err(args: &[&str], stdin: &str) -> (i32, String) {
+    let output = run(args, stdin, &env::temp_dir());
+    assert!(!output.status.success(), "wcase {:?} succeeded", args);
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(
+        wcase(&["convert", "--snake", "-w", "helloWorld"], ""),
+        "hello_world\n"
+    );
+    assert_eq!(
+        wcase(&["convert", "--to", "kebab", "-w", "HelloWorld"], ""),
+        "hello-world\n"
+    );
+    assert_eq!(
+        wcase(&["convert", "--for", "rust-const", "-w", "helloWorld"], ""),
+        "HELLO_WORLD\n"
+    );
+    assert_eq!(
+        wcase(&["convert", "--slug"], "Hello World!\nOf Mice & Men\n"),
+        "hello-world\nof-mice-men\n"
+    );
+    assert_eq!(
+        wcase(&["convert", "--snake", "--lang", "rust", "-w", "Type"], ""),
+        "r#type\n"
+    );
+
+    let (code, stderr) = wcase_err(&["convert", "--snake", "-w", "hello-World"], "");
+    assert_eq!((code, stderr.as_str()), (1, "Invalid input\n"));
+
+    for word in ["_", "__", " "] {
+        let (code, stderr) = wcase_err(&["--camel", "-w", word], "");
+        assert_eq!((code, stderr.as_str()), (1, "Invalid input\n"));
+    }
+}
+
+#[test]
+fn test_shorthand() {
+    let shorthands: [&[&str]; 3] = [
+        &["--snake", "-w", "helloWorld"],
+        &["--to", "snake", "-w", "helloWorld"],
+        &["--pascal", "-w", "hello_world"],
+    ];
+
+    for args in shorthands {
+        let mut subcommand = vec!["convert"];
+        subcommand.extend(args);
+
+        assert_eq!(wcase(args, ""), wcase(&subcommand, ""));
+    }
+
+    assert_eq!(wcase(&["-w", "hello_world"], ""), "snake\n");
+    assert_eq!(wcase(&["--slug", "-w", "Hello World"], ""), "hello-world\n");
+    assert_eq!(
+        wcase(
+            &[
+                "--define",
+                "k=k_{lower}_{lower}",
+                "--to",
+                "k",
+                "-w",
+                "fooBar"
+            ],
+            ""
+        ),
+        "k_foo_bar\n"
+    );
+}
+
+#[test]
+fn test_detect() {
+    assert_eq!(
+        wcase(&["detect", "helloWorld", "HELLO-WORLD", "hello-World"], ""),
+        "camel\ntrain\nnone\n"
+    );
+    assert_eq!(
+        wcase(&["detect"], "hello_world\nHello World\n"),
+        "snake\ntitle\n"
+    );
+    assert_eq!(
+        wcase(
+            &["--define", "k=k_{lower}_{lower}", "detect", "k_foo_bar"],
+            ""
+        ),
+        "k\n"
+    );
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(
+        wcase(&["check", "-e", "snake", "-w", "hello_world"], ""),
+        ""
+    );
+
+    let output = run(
+        &["check", "-e", "snake", "-s", "compiler"],
+        "hello_world helloWorld\n",
+        &env::temp_dir(),
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "<stdin>:1:13: \"helloWorld\" is camel case, expected snake case: hello_world\n"
+    );
+}
+
+#[test]
+fn test_json() {
+    assert_eq!(
+        wcase(
+            &["json", "--snake"],
+            r#"{"userId": 1, "nested": {"firstName": "Ada"}}"#
+        ),
+        "{\n  \"user_id\": 1,\n  \"nested\": {\n    \"first_name\": \"Ada\"\n  }\n}\n"
+    );
+}
+
+#[test]
+fn test_files() {
+    assert_eq!(
+        wcase(
+            &["files", "--from", "camel", "--snake", "--lang", "rust"],
+            "let userName = getName(); // userName\n"
+        ),
+        "let user_name = get_name(); // userName\n"
+    );
+
+    assert_eq!(
+        wcase(
+            &["files", "--from", "snake", "--camel", "--lang", "python"],
+            "user_name = 1\nprint(f\"{user_name}\")\n"
+        ),
+        "userName = 1\nprint(f\"{userName}\")\n"
+    );
+
+    assert_eq!(
+        wcase(
+            &[
+                "files",
+                "--from",
+                "snake",
+                "--camel",
+                "--lang",
+                "javascript"
+            ],
+            "const user_name = 1;\nlog(`${user_name}`);\n"
+        ),
+        "const userName = 1;\nlog(`${userName}`);\n"
+    );
+}
+
+#[test]
+fn test_rename() {
+    let dir = env::temp_dir().join(format!("wcase-cli-rename-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("MyReport.tar.gz"), "").unwrap();
+    fs::write(dir.join("Other File.txt"), "").unwrap();
+
+    let dry_run = run(&["rename", "--kebab", "-n", "MyReport.tar.gz"], "", &dir);
+    let renamed = run(
+        &["rename", "--kebab", "MyReport.tar.gz", "Other File.txt"],
+        "",
+        &dir,
+    );
+
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        String::from_utf8(dry_run.stdout).unwrap(),
+        "MyReport.tar.gz -> my-report.tar.gz\n"
+    );
+    assert!(renamed.status.success());
+    assert_eq!(names, ["my-report.tar.gz", "other-file.txt"]);
+}
+
+#[test]
+fn test_completions_and_man() {
+    assert!(wcase(&["completions", "bash"], "").contains("http_header"));
+    assert!(wcase(&["man"], "").contains(".SH SUBCOMMANDS"));
+}
+
+#[test]
+fn test_defined_case() {
+    let define = ["--define", "k=k_{lower}_{lower}"];
+
+    assert_eq!(
+        wcase(
+            &[&define[..], &["json", "--to", "k"]].concat(),
+            "{\"userName\": 1}"
+        ),
+        "{\n  \"k_user_name\": 1\n}\n"
+    );
+    assert_eq!(
+        wcase(
+            &[&define[..], &["yaml", "--to", "k"]].concat(),
+            "userName: 1\n"
+        ),
+        "k_user_name: 1\n"
+    );
+    assert_eq!(
+        wcase(
+            &[&define[..], &["csv", "--to", "k"]].concat(),
+            "userName,id\n"
+        ),
+        "k_user_name,k_id\n"
+    );
+    assert_eq!(
+        wcase(
+            &[
+                &define[..],
+                &["env", "-r", "-p", "app", "--to", "k", "APP_MAX_SIZE"]
+            ]
+            .concat(),
+            ""
+        ),
+        "k_max_size\n"
+    );
+    assert_eq!(
+        wcase(
+            &[
+                &define[..],
+                &["files", "--from", "camel", "-l", "rust", "--to", "k"]
+            ]
+            .concat(),
+            "let userName = 1;\n"
+        ),
+        "let k_user_name = 1;\n"
+    );
+}
+
+#[test]
+fn test_json_collision() {
+    let input = r#"{"fooBar": 1, "foo_bar": 2}"#;
+    let output = run(&["json", "--snake"], input, &env::temp_dir());
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\n  \"foo_bar\": 2\n}\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Key collision at /: \"fooBar\" and \"foo_bar\" both become \"foo_bar\", \
+         keeping the value of \"foo_bar\"\n"
+    );
+
+    let (code, stderr) = wcase_err(&["json", "--snake", "--strict"], input);
+    assert_eq!(code, 1);
+    assert!(stderr.starts_with("Key collision at /"));
+}
+
+#[test]
+fn test_sanitize() {
+    assert_eq!(
+        wcase(&["--snake", "--sanitize", "-w", "3D Printer's Queue"], ""),
+        "_3d_printers_queue\n"
+    );
+}
+
+#[test]
+fn test_max_len() {
+    assert_eq!(
+        wcase(
+            &["--snake", "--max-len", "8"],
+            "customer_account_number\ncustomer_account_name\n"
+        ),
+        "cs_ac_nm\nc_a_nm_2\n"
+    );
+}
+
+#[test]
+fn test_ascii() {
+    assert_eq!(
+        wcase(&["--snake", "--ascii", "-w", "CaféCrème"], ""),
+        "cafe_creme\n"
+    );
+
+    let (code, stderr) = wcase_err(&["--snake", "--ascii", "-w", "東京"], "");
+    assert_eq!(
+        (code, stderr.as_str()),
+        (1, "Nothing is left of \"東京\"\n")
+    );
+}
+
+#[test]
+fn test_config_case() {
+    let dir = env::temp_dir().join(format!("wcase-cli-config-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(".wcase.toml"), "case = \"snake\"\n").unwrap();
+
+    // only the file of the directory is read, not the ones of the machine
+    let run_with_config = |args: &[&str], stdin: &str| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_wcase"))
+            .args(args)
+            .current_dir(&dir)
+            .env("HOME", &dir)
+            .env("XDG_CONFIG_HOME", &dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+        child.wait_with_output().unwrap()
+    };
+    let with_config = |args: &[&str], stdin: &str| {
+        let output = run_with_config(args, stdin);
+        assert!(output.status.success(), "wcase {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let detected = with_config(&["-w", "helloWorld"], "");
+    let sanitized = with_config(&["--sanitize", "-w", "Hello World!"], "");
+    let converted = with_config(&["convert", "-w", "helloWorld"], "");
+    let json = with_config(&["json"], "{\"userName\": 1}");
+    let shown = with_config(&["config", "show"], "");
+
+    fs::write(dir.join(".wcase.toml"), "case = \"shout\"\n").unwrap();
+    let invalid = run_with_config(&["json"], "{}");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(detected, "camel\n");
+    assert_eq!(sanitized, "hello_world\n");
+    assert_eq!(converted, "hello_world\n");
+    assert_eq!(json, "{\n  \"user_name\": 1\n}\n");
+    assert!(shown.contains("snake"), "{}", shown);
+
+    assert_eq!(invalid.status.code(), Some(1));
+    assert!(String::from_utf8(invalid.stderr)
+        .unwrap()
+        .ends_with(".wcase.toml: Unknown case \"shout\"\n"));
+}
+
+#[test]
+fn test_convert_options_before_a_subcommand() {
+    let (code, stderr) = wcase_err(&["--snake", "json"], "{}");
+    assert_eq!(code, 2);
+    assert!(stderr.starts_with(
+        "error: --snake is an option of convert, it can't come before the json subcommand"
+    ));
+
+    let (code, stderr) = wcase_err(&["-w", "helloWorld", "detect", "hello_world"], "");
+    assert_eq!(code, 2);
+    assert!(stderr.contains("--word <WORD> is an option of convert"));
+
+    assert_eq!(
+        wcase(&["json", "--snake"], r#"{"fooBar": 1}"#),
+        "{\n  \"foo_bar\": 1\n}\n"
+    );
+}
+
+#[test]
+fn test_env_reverse_skipped_keys() {
+    let output = run(
+        &["env", "--reverse", "-p", "app", "--snake"],
+        "APP_POOL_SIZE=4\nHOME=/root\nAPP_=x\n",
+        &env::temp_dir(),
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "pool_size=4\nHOME=/root\nAPP_=x\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "\"HOME\" doesn't start with the prefix, keeping the key as it is\n\
+         \"APP_\" has no key after the prefix, keeping the key as it is\n"
+    );
+}
@@ -0,0 +1,31 @@
+//! Builds the library without its default features, where it is `no_std` and
+//! any use of std fails to compile. Set `WCASE_NO_STD_TARGET` to a target with
+//! no std at all, like `thumbv7em-none-eabihf`, to build for it instead.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_builds_without_std() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut command = Command::new(env!("CARGO"));
+
+    command
+        .current_dir(root)
+        .args(["build", "--quiet", "--lib", "--no-default-features"])
+        .arg("--target-dir")
+        .arg(root.join("target").join("no-std"));
+
+    if let Ok(target) = env::var("WCASE_NO_STD_TARGET") {
+        command.args(["--target", &target]);
+    }
+
+    let output = command.output().expect("Could not run cargo");
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,11 @@
+#!/bin/bash
+
+set -o errexit
+set -o nounset
+
+cd "$(dirname "$0")/.."
+
+cargo rustc --lib --release --target wasm32-unknown-unknown \
+  --no-default-features --features wasm --crate-type cdylib
+
+cp ./target/wasm32-unknown-unknown/release/wcase.wasm ./wasm
@@ -0,0 +1,16 @@
+// Generated from the cases of wcase, do not edit.
+
+export declare enum Case {
+  Flat = 0,
+  Upper = 1,
+  Camel = 2,
+  Pascal = 3,
+  Snake = 4,
+  AllCaps = 5,
+  Kebab = 6,
+  Train = 7,
+  Spaced = 8,
+  Title = 9,
+  HttpHeader = 10,
+  None = 11,
+}
@@ -0,0 +1,12 @@
+import { Case } from "./case.js";
+
+export { Case };
+
+/** Loads the module, from the bytes of `wcase.wasm` or a compiled module. */
+export declare function init(source: BufferSource | WebAssembly.Module): Promise<void>;
+
+/** The case of a word, `Case.None` when it has none. */
+export declare function detect(text: string): Case;
+
+/** Converts a word like `wcase -w`, throwing when it has no case. */
+export declare function convert(text: string, to: Case): string;
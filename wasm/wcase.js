@@ -0,0 +1,67 @@
+import { Case } from "./case.js";
+
+export { Case };
+
+const INVALID_INPUT = -1;
+const UNKNOWN_CASE = -2;
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+let exports;
+
+export async function init(source) {
+  const result = await WebAssembly.instantiate(source);
+  exports = (result.instance ?? result).exports;
+}
+
+function bytes(ptr, len) {
+  return new Uint8Array(exports.memory.buffer, ptr, len);
+}
+
+function withText(text, f) {
+  if (exports === undefined) {
+    throw new Error("Call init() before using wcase");
+  }
+
+  const encoded = encoder.encode(text);
+  const ptr = exports.wcase_wasm_alloc(encoded.length);
+  bytes(ptr, encoded.length).set(encoded);
+
+  try {
+    return f(ptr, encoded.length);
+  } finally {
+    exports.wcase_wasm_free(ptr, encoded.length);
+  }
+}
+
+export function detect(text) {
+  return withText(text, (ptr, len) => exports.wcase_wasm_detect(ptr, len));
+}
+
+export function convert(text, to) {
+  return withText(text, (ptr, len) => {
+    let capacity = len + 16;
+
+    for (;;) {
+      const out = exports.wcase_wasm_alloc(capacity);
+      let written;
+
+      try {
+        written = exports.wcase_wasm_convert(ptr, len, to, out, capacity);
+
+        if (written === INVALID_INPUT) {
+          throw new Error(`Invalid input "${text}"`);
+        } else if (written === UNKNOWN_CASE) {
+          throw new Error(`Unknown case ${to}`);
+        } else if (written <= capacity) {
+          return decoder.decode(bytes(out, written));
+        }
+      } finally {
+        exports.wcase_wasm_free(out, capacity);
+      }
+
+      capacity = written;
+    }
+  });
+}
@@ -0,0 +1,31 @@
+// Run with `./wasm/build.sh && node --test wasm/`.
+
+import assert from "node:assert/strict";
+import { readFile } from "node:fs/promises";
+import { before, test } from "node:test";
+import { Case, convert, detect, init } from "./wcase.js";
+
+before(async () => {
+  await init(await readFile(new URL("./wcase.wasm", import.meta.url)));
+});
+
+test("detect", () => {
+  assert.equal(detect("helloWorld"), Case.Camel);
+  assert.equal(detect("Hello-World"), Case.HttpHeader);
+  assert.equal(detect("hello_World-x"), Case.None);
+});
+
+test("convert", () => {
+  assert.equal(convert("helloWorld", Case.Snake), "hello_world");
+  assert.equal(convert("hello_world", Case.HttpHeader), "Hello-World");
+  assert.equal(convert("größe_maß", Case.AllCaps), "GRÖSSE_MASS");
+  assert.equal(convert("ŉ".repeat(20), Case.Upper), "ʼN".repeat(20));
+  assert.equal(convert("", Case.Pascal), "");
+  assert.equal(convert("_", Case.Camel), "");
+  assert.equal(convert("_", Case.Snake), "_");
+});
+
+test("errors", () => {
+  assert.throws(() => convert("hello_World-x", Case.Snake), /Invalid input/);
+  assert.throws(() => convert("hello", Case.None), /Unknown case/);
+});
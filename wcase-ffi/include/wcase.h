@@ -0,0 +1,87 @@
+/* Generated from wcase-ffi by cbindgen, do not edit. */
+
+#ifndef WCASE_H
+#define WCASE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// What a function of the API returns, `wcase_status_message` describes it.
+typedef enum {
+    // no error
+    WCASE_STATUS_OK = 0,
+    // a pointer argument is null
+    WCASE_STATUS_NULL_POINTER = 1,
+    // the text isn't valid utf-8
+    WCASE_STATUS_INVALID_UTF8 = 2,
+    // the text has no case to convert from
+    WCASE_STATUS_NO_CASE = 3,
+    // the case to convert to is unknown
+    WCASE_STATUS_UNKNOWN_CASE = 4,
+    // a bug of wcase, which can't unwind into C
+    WCASE_STATUS_PANIC = 5,
+} wcase_status;
+
+// The number of a case in C, one of the `WCASE_*` constants.
+typedef uint32_t wcase_case;
+
+#define WCASE_FLAT 0
+
+#define WCASE_UPPER 1
+
+#define WCASE_CAMEL 2
+
+#define WCASE_PASCAL 3
+
+#define WCASE_SNAKE 4
+
+#define WCASE_ALL_CAPS 5
+
+#define WCASE_KEBAB 6
+
+#define WCASE_TRAIN 7
+
+#define WCASE_SPACED 8
+
+#define WCASE_TITLE 9
+
+#define WCASE_HTTP_HEADER 10
+
+#define WCASE_NONE 255
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Writes the case of `text` to `case_out`, `WCASE_NONE` when it has none.
+//
+// # Safety
+//
+// `text` must be a nul terminated string and `case_out` writable.
+wcase_status wcase_detect(const char *text, wcase_case *case_out);
+
+// Converts `text` to the case `to`, writing a new string to `out` to
+// release with `wcase_free`.
+//
+// # Safety
+//
+// `text` must be a nul terminated string and `out` writable.
+wcase_status wcase_convert(const char *text, wcase_case to, char **out);
+
+// Releases a string of `wcase_convert`, doing nothing for null.
+//
+// # Safety
+//
+// `text` must come from `wcase_convert` and not be used afterwards.
+void wcase_free(char *text);
+
+// A static description of a status.
+const char *wcase_status_message(uint32_t status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WCASE_H */
@@ -0,0 +1,266 @@
+//! C API of wcase, declared in `include/wcase.h`. Strings are nul terminated
+//! utf-8, converted ones are owned by the caller and released with
+//! `wcase_free`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use wcase::{convert, get_case, Case};
+
+/// The number of a case in C, one of the `WCASE_*` constants.
+pub type CaseNumber = u32;
+
+pub const WCASE_FLAT: CaseNumber = 0;
+pub const WCASE_UPPER: CaseNumber = 1;
+pub const WCASE_CAMEL: CaseNumber = 2;
+pub const WCASE_PASCAL: CaseNumber = 3;
+pub const WCASE_SNAKE: CaseNumber = 4;
+pub const WCASE_ALL_CAPS: CaseNumber = 5;
+pub const WCASE_KEBAB: CaseNumber = 6;
+pub const WCASE_TRAIN: CaseNumber = 7;
+pub const WCASE_SPACED: CaseNumber = 8;
+pub const WCASE_TITLE: CaseNumber = 9;
+pub const WCASE_HTTP_HEADER: CaseNumber = 10;
+pub const WCASE_NONE: CaseNumber = 255;
+
+/// Cases by their number in C. Numbers are part of the API: new cases go
+/// before `Case::None`, which keeps its own number.
+const CASES: [(Case, CaseNumber); 12] = [
+    (Case::Flat, WCASE_FLAT),
+    (Case::Upper, WCASE_UPPER),
+    (Case::Camel, WCASE_CAMEL),
+    (Case::Pascal, WCASE_PASCAL),
+    (Case::Snake, WCASE_SNAKE),
+    (Case::AllCaps, WCASE_ALL_CAPS),
+    (Case::Kebab, WCASE_KEBAB),
+    (Case::Train, WCASE_TRAIN),
+    (Case::Spaced, WCASE_SPACED),
+    (Case::Title, WCASE_TITLE),
+    (Case::HttpHeader, WCASE_HTTP_HEADER),
+    (Case::None, WCASE_NONE),
+];
+
+/// What a function of the API returns, `wcase_status_message` describes it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    /// no error
+    Ok = 0,
+    /// a pointer argument is null
+    NullPointer = 1,
+    /// the text isn't valid utf-8
+    InvalidUtf8 = 2,
+    /// the text has no case to convert from
+    NoCase = 3,
+    /// the case to convert to is unknown
+    UnknownCase = 4,
+    /// a bug of wcase, which can't unwind into C
+    Panic = 5,
+}
+
+const STATUSES: [(Status, &CStr); 6] = [
+    (Status::Ok, c"No error"),
+    (Status::NullPointer, c"A pointer argument is null"),
+    (Status::InvalidUtf8, c"The text isn't valid utf-8"),
+    (Status::NoCase, c"The text has no case to convert from"),
+    (Status::UnknownCase, c"The case to convert to is unknown"),
+    (Status::Panic, c"Internal error"),
+];
+
+fn number(case: Case) -> u32 {
+    CASES.iter().find(|(c, _)| *c == case).unwrap().1
+}
+
+/// # Safety
+///
+/// `text` must be null or a nul terminated string.
+unsafe fn text<'a>(text: *const c_char) -> Result<&'a str, Status> {
+    if text.is_null() {
+        return Err(Status::NullPointer);
+    }
+
+    CStr::from_ptr(text)
+        .to_str()
+        .map_err(|_| Status::InvalidUtf8)
+}
+
+/// Runs `f`, turning a panic into `Status::Panic` as it can't unwind into C.
+fn guard(f: impl FnOnce() -> Result<(), Status>) -> Status {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => Status::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => Status::Panic,
+    }
+}
+
+/// Writes the case of `text` to `case_out`, `WCASE_NONE` when it has none.
+///
+/// # Safety
+///
+/// `text` must be a nul terminated string and `case_out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_detect(text: *const c_char, case_out: *mut CaseNumber) -> Status {
+    guard(|| {
+        let text = self::text(text)?;
+
+        if case_out.is_null() {
+            return Err(Status::NullPointer);
+        }
+
+        *case_out = number(get_case(text));
+        Ok(())
+    })
+}
+
+/// Converts `text` to the case `to`, writing a new string to `out` to
+/// release with `wcase_free`.
+///
+/// # Safety
+///
+/// `text` must be a nul terminated string and `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_convert(
+    text: *const c_char,
+    to: CaseNumber,
+    out: *mut *mut c_char,
+) -> Status {
+    guard(|| {
+        let text = self::text(text)?;
+
+        if out.is_null() {
+            return Err(Status::NullPointer);
+        }
+
+        let to = match CASES.iter().find(|(_, n)| *n == to) {
+            Some((Case::None, _)) | None => return Err(Status::UnknownCase),
+            Some((case, _)) => *case,
+        };
+
+        let case = get_case(text);
+
+        if case == Case::None {
+            return Err(Status::NoCase);
+        }
+
+        // the input has no nul, nor has its conversion
+        let converted = CString::new(convert(text, case, to)).unwrap();
+        *out = converted.into_raw();
+        Ok(())
+    })
+}
+
+/// Releases a string of `wcase_convert`, doing nothing for null.
+///
+/// # Safety
+///
+/// `text` must come from `wcase_convert` and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn wcase_free(text: *mut c_char) {
+    if !text.is_null() {
+        drop(CString::from_raw(text));
+    }
+}
+
+/// A static description of a status.
+#[no_mangle]
+pub extern "C" fn wcase_status_message(status: u32) -> *const c_char {
+    STATUSES
+        .iter()
+        .find(|(s, _)| *s as u32 == status)
+        .map_or(c"Unknown status".as_ptr(), |(_, message)| message.as_ptr())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::{env, fs, ptr};
+
+    /// The C header, generated by cbindgen from the items above.
+    fn header() -> String {
+        let dir = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(Path::new(dir).join("cbindgen.toml")).unwrap();
+        let mut header = Vec::new();
+
+        cbindgen::generate_with_config(dir, config)
+            .unwrap()
+            .write(&mut header);
+        String::from_utf8(header).unwrap()
+    }
+
+    fn detect(text: &CStr) -> Result<Case, Status> {
+        let mut number = 0;
+
+        match unsafe { wcase_detect(text.as_ptr(), &mut number) } {
+            Status::Ok => Ok(CASES.iter().find(|(_, n)| *n == number).unwrap().0),
+            status => Err(status),
+        }
+    }
+
+    fn convert(text: &CStr, to: u32) -> Result<String, Status> {
+        let mut out = ptr::null_mut();
+
+        match unsafe { wcase_convert(text.as_ptr(), to, &mut out) } {
+            Status::Ok => unsafe {
+                let converted = CStr::from_ptr(out).to_str().unwrap().to_string();
+                wcase_free(out);
+                Ok(converted)
+            },
+            status => {
+                assert!(out.is_null());
+                Err(status)
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect(c"helloWorld"), Ok(Case::Camel));
+        assert_eq!(detect(c"hello_World-x"), Ok(Case::None));
+        assert_eq!(detect(c"caf\xe9"), Err(Status::InvalidUtf8));
+        assert_eq!(
+            unsafe { wcase_detect(ptr::null(), &mut 0) },
+            Status::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(convert(c"helloWorld", 4), Ok("hello_world".to_string()));
+        assert_eq!(convert(c"hello world", 10), Ok("Hello-World".to_string()));
+        assert_eq!(convert(c"", WCASE_PASCAL), Ok(String::new()));
+        assert_eq!(convert(c"", WCASE_SNAKE), Ok(String::new()));
+        assert_eq!(convert(c"_", WCASE_CAMEL), Ok(String::new()));
+        assert_eq!(convert(c"_", WCASE_TITLE), Ok(String::new()));
+        assert_eq!(convert(c"_", WCASE_KEBAB), Ok("-".to_string()));
+        assert_eq!(convert(c"hello_World-x", 4), Err(Status::NoCase));
+        assert_eq!(convert(c"helloWorld", 255), Err(Status::UnknownCase));
+        assert_eq!(convert(c"helloWorld", 11), Err(Status::UnknownCase));
+        assert_eq!(convert(c"caf\xe9", 4), Err(Status::InvalidUtf8));
+        assert_eq!(
+            unsafe { wcase_convert(c"x".as_ptr(), 4, ptr::null_mut()) },
+            Status::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_status_message() {
+        let message = unsafe { CStr::from_ptr(wcase_status_message(Status::NoCase as u32)) };
+        assert_eq!(message, c"The text has no case to convert from");
+    }
+
+    /// Compares `include/wcase.h`, or writes it with `WCASE_UPDATE=1`.
+    #[test]
+    fn test_header_is_up_to_date() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/wcase.h");
+        let expected = header();
+
+        if env::var_os("WCASE_UPDATE").is_some() {
+            fs::write(&path, &expected).unwrap();
+        }
+
+        assert!(
+            fs::read_to_string(&path).unwrap_or_default() == expected,
+            "include/wcase.h is out of date, run WCASE_UPDATE=1 cargo test -p wcase-ffi"
+        );
+    }
+}
@@ -0,0 +1,473 @@
+//! Case conversion at compile time, with the converters of `wcase`.
+//!
+//! ```ignore
+//! use wcase_macros::{paste, rename_all, wcase};
+//!
+//! const TABLE: &str = wcase!(snake, "UserAccount"); // "user_account"
+//!
+//! paste! {
+//!     fn [<get_ UserAccount:snake>]() {} // fn get_user_account()
+//! }
+//!
+//! #[rename_all("kebab")]
+//! enum Mode { FastForward, Rebase } // Mode::VARIANT_NAMES == ["fast-forward", "rebase"]
+//! ```
+
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
+use quote::quote;
+use std::str::FromStr;
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Attribute, Data, DeriveInput, Fields, LitStr, Token};
+use wcase::custom::{CustomCase, Target};
+use wcase::{get_case, split_words, Case};
+
+/// Converts a string literal to a case: `wcase!(snake, "HelloWorld")` gives
+/// `"hello_world"`. The case is a name or a template like `"k_{lower}_{lower}"`.
+#[proc_macro]
+pub fn wcase(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse2::<Convert>(input.into()).and_then(|convert| convert.expand()) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Concatenates the segments of `[< ... >]` into an identifier, converting
+/// those followed by `:case`: `[<get_ UserAccount:snake>]` gives
+/// `get_user_account`.
+#[proc_macro]
+pub fn paste(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match paste_tokens(input.into()) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Renames the fields of a struct or the variants of an enum, adding
+/// `FIELD_NAMES` or `VARIANT_NAMES` and `variant_name()`, and the matching
+/// `#[serde(rename)]` when the item derives `Serialize` or `Deserialize`.
+#[proc_macro_attribute]
+pub fn rename_all(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match rename_all_tokens(attr.into(), item.into()) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn parse_target(name: &str) -> Result<Target, String> {
+    if name.contains('{') {
+        return CustomCase::new("custom", name).map(Target::Custom);
+    }
+
+    Case::from_str(name).map(Target::Case).map_err(|_| {
+        format!(
+            "Unknown case \"{}\", use flat, upper, camel, pascal, snake, all_caps, kebab, train, \
+             spaced, title, http_header or a template like \"k_{{lower}}_{{lower}}\"",
+            name
+        )
+    })
+}
+
+/// Converts a name `get_case` accepts, splitting it like the lenient
+/// converters so acronyms stay together: `HTTPServer` gives `http_server`.
+fn convert_name(name: &str, target: &Target) -> Result<String, String> {
+    if get_case(name) == Case::None {
+        return Err(format!(
+            "Cannot detect the case of \"{}\", use a single kind of separator like \
+             hello_world, hello-world or helloWorld",
+            name
+        ));
+    }
+
+    Ok(target.join(&split_words(name)))
+}
+
+/// The arguments of `wcase!`: a case and a string literal.
+struct Convert {
+    target: Target,
+    text: LitStr,
+}
+
+impl Parse for Convert {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (name, span) = if input.peek(LitStr) {
+            let name: LitStr = input.parse()?;
+            (name.value(), name.span())
+        } else {
+            let name: Ident = input.parse()?;
+            (name.to_string(), name.span())
+        };
+
+        let target = parse_target(&name).map_err(|e| syn::Error::new(span, e))?;
+        input.parse::<Token![,]>()?;
+        let text = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Convert { target, text })
+    }
+}
+
+impl Convert {
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let converted = convert_name(&self.text.value(), &self.target)
+            .map_err(|e| syn::Error::new(self.text.span(), e))?;
+        let converted = LitStr::new(&converted, self.text.span());
+
+        Ok(quote!(#converted))
+    }
+}
+
+fn paste_tokens(input: TokenStream) -> syn::Result<TokenStream> {
+    let mut output = TokenStream::new();
+
+    for tree in input {
+        let tree = match tree {
+            TokenTree::Group(group) if is_paste_group(&group) => {
+                TokenTree::Ident(paste_ident(&group)?)
+            }
+            TokenTree::Group(group) => {
+                let mut pasted = Group::new(group.delimiter(), paste_tokens(group.stream())?);
+                pasted.set_span(group.span());
+                TokenTree::Group(pasted)
+            }
+            tree => tree,
+        };
+
+        output.extend([tree]);
+    }
+
+    Ok(output)
+}
+
+fn is_paste_group(group: &Group) -> bool {
+    let trees: Vec<TokenTree> = group.stream().into_iter().collect();
+    let is_punct = |tree: Option<&TokenTree>, c: char| match tree {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == c,
+        _ => false,
+    };
+
+    group.delimiter() == Delimiter::Bracket
+        && trees.len() >= 2
+        && is_punct(trees.first(), '<')
+        && is_punct(trees.last(), '>')
+}
+
+/// Flattens the segments of a paste group, undoing the invisible groups
+/// `macro_rules!` puts around its fragments.
+fn segments(stream: TokenStream, segments: &mut Vec<TokenTree>) {
+    for tree in stream {
+        match tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                self::segments(group.stream(), segments)
+            }
+            tree => segments.push(tree),
+        }
+    }
+}
+
+fn paste_ident(group: &Group) -> syn::Result<Ident> {
+    let mut trees = Vec::new();
+    segments(group.stream(), &mut trees);
+    let trees = &trees[1..trees.len() - 1];
+
+    let mut name = String::new();
+    let mut i = 0;
+
+    while i < trees.len() {
+        let tree = &trees[i];
+        let mut segment = match tree {
+            TokenTree::Ident(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+            TokenTree::Literal(literal) => match syn::parse2::<LitStr>(quote!(#literal)) {
+                Ok(text) => text.value(),
+                Err(_) => literal.to_string(),
+            },
+            tree => {
+                return Err(syn::Error::new(
+                    tree.span(),
+                    "Expected a segment of identifier",
+                ))
+            }
+        };
+
+        let is_colon = match trees.get(i + 1) {
+            Some(TokenTree::Punct(punct)) => punct.as_char() == ':',
+            _ => false,
+        };
+
+        if is_colon {
+            let case = match trees.get(i + 2) {
+                Some(TokenTree::Ident(case)) => case,
+                _ => {
+                    return Err(syn::Error::new(
+                        trees[i + 1].span(),
+                        "Expected a case after :",
+                    ))
+                }
+            };
+
+            let target =
+                parse_target(&case.to_string()).map_err(|e| syn::Error::new(case.span(), e))?;
+            segment =
+                convert_name(&segment, &target).map_err(|e| syn::Error::new(tree.span(), e))?;
+            i += 2;
+        }
+
+        name.push_str(&segment);
+        i += 1;
+    }
+
+    if syn::parse_str::<Ident>(&name).is_err() {
+        let message = format!("\"{}\" is not a valid identifier", name);
+        return Err(syn::Error::new(group.span(), message));
+    }
+
+    Ok(Ident::new(&name, group.span()))
+}
+
+fn rename_all_tokens(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let name: LitStr = syn::parse2(attr)?;
+    let target = parse_target(&name.value()).map_err(|e| syn::Error::new(name.span(), e))?;
+    let mut input: DeriveInput = syn::parse2(item)?;
+
+    let uses_serde = derives_serde(&input.attrs)?;
+    let rename = |ident: &Ident| -> syn::Result<LitStr> {
+        let name = ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let renamed = convert_name(name, &target).map_err(|e| syn::Error::new(ident.span(), e))?;
+
+        Ok(LitStr::new(&renamed, ident.span()))
+    };
+
+    let (ident, generics) = (input.ident.clone(), input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let items = match &mut input.data {
+        Data::Struct(data) => {
+            let fields = match &mut data.fields {
+                Fields::Named(fields) => fields,
+                _ => {
+                    let message = "rename_all needs a struct with named fields";
+                    return Err(syn::Error::new(ident.span(), message));
+                }
+            };
+
+            let mut names = Vec::new();
+
+            for field in fields.named.iter_mut() {
+                let name = rename(field.ident.as_ref().unwrap())?;
+
+                if uses_serde && !has_serde_rename(&field.attrs)? {
+                    field.attrs.push(parse_quote!(#[serde(rename = #name)]));
+                }
+
+                names.push(name);
+            }
+
+            quote! {
+                pub const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+            }
+        }
+        Data::Enum(data) => {
+            let mut names = Vec::new();
+            let mut variants = Vec::new();
+            let mut units = Vec::new();
+
+            for variant in data.variants.iter_mut() {
+                let name = rename(&variant.ident)?;
+
+                if uses_serde && !has_serde_rename(&variant.attrs)? {
+                    variant.attrs.push(parse_quote!(#[serde(rename = #name)]));
+                }
+
+                if matches!(variant.fields, Fields::Unit) {
+                    units.push(variant.ident.clone());
+                }
+
+                names.push(name);
+                variants.push(variant.ident.clone());
+            }
+
+            let from_name = match units.len() == variants.len() {
+                true => quote! {
+                    pub fn from_variant_name(name: &str) -> Option<Self> {
+                        match name {
+                            #(#names => Some(Self::#units),)*
+                            _ => None,
+                        }
+                    }
+                },
+                false => quote!(),
+            };
+
+            quote! {
+                pub const VARIANT_NAMES: &'static [&'static str] = &[#(#names),*];
+
+                pub fn variant_name(&self) -> &'static str {
+                    match self {
+                        #(Self::#variants { .. } => #names,)*
+                    }
+                }
+
+                #from_name
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "rename_all works on structs and enums",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        #input
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #items
+        }
+    })
+}
+
+fn derives_serde(attrs: &[Attribute]) -> syn::Result<bool> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        let paths = attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)?;
+
+        let is_serde = paths.iter().any(|path| {
+            path.segments.last().is_some_and(|segment| {
+                segment.ident == "Serialize" || segment.ident == "Deserialize"
+            })
+        });
+
+        if is_serde {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Whether a `serde` attribute renames the field or variant, as in
+/// `#[serde(rename = "id")]` or `#[serde(default, rename(serialize = "id"))]`.
+fn has_serde_rename(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut renamed = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            renamed |= meta.path.is_ident("rename");
+            skip_meta_value(&meta)
+        })?;
+    }
+
+    Ok(renamed)
+}
+
+/// Consumes the value of a serde option, `= "..."` or `(...)`, if it has one.
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta_value(&nested))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paste(input: &str) -> String {
+        let input: TokenStream = input.parse().unwrap();
+
+        match paste_tokens(input) {
+            Ok(output) => output.to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_convert_name() {
+        let snake = Target::Case(Case::Snake);
+        let key = Target::Custom(CustomCase::new("key", "k_{lower}_{lower}").unwrap());
+
+        assert_eq!(convert_name("HelloWorld", &snake).unwrap(), "hello_world");
+        assert_eq!(convert_name("HelloWorld", &key).unwrap(), "k_hello_world");
+        assert!(convert_name("hello_World-x", &snake)
+            .unwrap_err()
+            .starts_with("Cannot detect the case of \"hello_World-x\""));
+        assert!(parse_target("shout").is_err());
+    }
+
+    #[test]
+    fn test_paste() {
+        assert_eq!(
+            paste("fn [<get_ UserAccount:snake>]() {}"),
+            "fn get_user_account () { }"
+        );
+        assert_eq!(
+            paste("const [<user_account:all_caps _ID>]: u8 = 0;"),
+            "const USER_ACCOUNT_ID : u8 = 0 ;"
+        );
+        assert_eq!(
+            paste("impl X { fn [<with_ \"field-name\":snake>]() {} }"),
+            "impl X { fn with_field_name () { } }"
+        );
+        assert_eq!(
+            paste("fn [<Hello_World-x:snake>]() {}"),
+            "Expected a segment of identifier"
+        );
+        assert_eq!(paste("fn [<1 x>]() {}"), "\"1x\" is not a valid identifier");
+    }
+
+    #[test]
+    fn test_rename_all() {
+        let expand = |attr: &str, item: &str| {
+            rename_all_tokens(attr.parse().unwrap(), item.parse().unwrap())
+                .map(|output| output.to_string())
+                .unwrap_or_else(|e| e.to_string())
+        };
+
+        let output = expand(
+            "\"kebab\"",
+            "#[derive(Serialize)] struct User { user_id: u32, #[serde(rename = \"n\")] name: String }",
+        );
+        assert!(output.contains("# [serde (rename = \"user-id\")] user_id"));
+        assert!(!output.contains("rename = \"name\""));
+        assert!(
+            output.contains("FIELD_NAMES : & 'static [& 'static str] = & [\"user-id\" , \"name\"]")
+        );
+
+        let output = expand(
+            "\"kebab\"",
+            "#[derive(Deserialize)] struct User { \
+             #[serde(deserialize_with = \"renamed_id\")] user_id: u32, \
+             #[serde(with = \"renamer\", alias = \"id\")] account_id: u32, \
+             #[serde(default, rename(deserialize = \"n\"))] name: String }",
+        );
+        assert!(output.contains("# [serde (rename = \"user-id\")] user_id"));
+        assert!(output.contains("# [serde (rename = \"account-id\")] account_id"));
+        assert!(!output.contains("rename = \"name\""));
+        assert!(expand(
+            "\"kebab\"",
+            "#[derive(Serialize)] struct A { #[serde(rename = )] b: u8 }"
+        )
+        .starts_with("unexpected end of input"));
+
+        let output = expand("\"snake\"", "enum Mode { FastForward, Rebase }");
+        assert!(!output.contains("serde"));
+        assert!(output.contains("\"fast_forward\" => Some (Self :: FastForward)"));
+
+        assert_eq!(
+            expand("\"shout\"", "struct A { b: u8 }"),
+            parse_target("shout").unwrap_err()
+        );
+        assert_eq!(
+            expand("\"kebab\"", "struct A(u8);"),
+            "rename_all needs a struct with named fields"
+        );
+    }
+}
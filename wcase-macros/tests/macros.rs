@@ -0,0 +1,64 @@
+use wcase_macros::{paste, rename_all, wcase};
+
+#[rename_all("kebab")]
+#[derive(Debug, PartialEq)]
+enum Mode {
+    FastForward,
+    Rebase,
+}
+
+#[rename_all("camel")]
+struct User {
+    user_id: u32,
+    r#type: String,
+}
+
+#[rename_all("m_{lower}{capital}")]
+#[allow(dead_code)]
+enum Event {
+    KeyDown { code: u32 },
+    Resize(u32, u32),
+}
+
+macro_rules! getters {
+    ($($name:ident),*) => {
+        paste! {
+            $(fn [<get_ $name:snake>]() -> &'static str { stringify!($name) })*
+        }
+    };
+}
+
+getters!(UserAccount, HTTPServer);
+
+#[test]
+fn test_wcase() {
+    assert_eq!(wcase!(snake, "HelloWorld"), "hello_world");
+    assert_eq!(wcase!("all-caps", "hello-world"), "HELLO_WORLD");
+    assert_eq!(wcase!("k_{lower}_{lower}", "helloWorld"), "k_hello_world");
+}
+
+#[test]
+fn test_paste() {
+    assert_eq!(get_user_account(), "UserAccount");
+    assert_eq!(get_http_server(), "HTTPServer");
+}
+
+#[test]
+fn test_rename_all() {
+    assert_eq!(Mode::VARIANT_NAMES, ["fast-forward", "rebase"]);
+    assert_eq!(Mode::Rebase.variant_name(), "rebase");
+    assert_eq!(
+        Mode::from_variant_name("fast-forward"),
+        Some(Mode::FastForward)
+    );
+    assert_eq!(User::FIELD_NAMES, ["userId", "type"]);
+    assert_eq!(Event::KeyDown { code: 1 }.variant_name(), "m_keyDown");
+    assert_eq!(Event::Resize(1, 2).variant_name(), "m_resize");
+
+    let user = User {
+        user_id: 1,
+        r#type: String::new(),
+    };
+    assert_eq!(user.user_id, 1);
+    assert!(user.r#type.is_empty());
+}
@@ -0,0 +1,18 @@
+#!/bin/bash
+
+# Builds the extension into python/wcase, to run the tests without installing
+# the package. Wheels are built with `maturin build --release`.
+
+set -o errexit
+set -o nounset
+
+cd "$(dirname "$0")/.."
+
+cargo build --release -p wcase-python
+
+case "$(uname)" in
+  Darwin) declare library=lib_wcase.dylib ;;
+  *) declare library=lib_wcase.so ;;
+esac
+
+cp ./target/release/${library} ./wcase-python/python/wcase/_wcase.abi3.so
@@ -0,0 +1,23 @@
+"""Detects and converts the case of words, with the `wcase._wcase` extension.
+
+Build the package with maturin, or the extension into `python/wcase` with
+`build.sh` to run the tests.
+"""
+
+from enum import IntEnum
+
+from . import _wcase
+
+__all__ = ["Case", "detect", "convert", "convert_many"]
+
+Case = IntEnum("Case", _wcase.CASES, module=__name__)
+Case.__doc__ = "The cases of wcase, by the number the extension gives them."
+
+
+def detect(text):
+    """The case of a word, `Case.NONE` when it has none."""
+    return Case(_wcase.detect(text))
+
+
+convert = _wcase.convert
+convert_many = _wcase.convert_many
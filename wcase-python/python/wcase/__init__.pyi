@@ -0,0 +1,30 @@
+from enum import IntEnum
+from typing import Iterable, List, Union
+
+__all__ = ["Case", "detect", "convert", "convert_many"]
+
+class Case(IntEnum):
+    """The cases of wcase, by the number the extension gives them."""
+
+    FLAT = 0
+    UPPER = 1
+    CAMEL = 2
+    PASCAL = 3
+    SNAKE = 4
+    ALL_CAPS = 5
+    KEBAB = 6
+    TRAIN = 7
+    SPACED = 8
+    TITLE = 9
+    HTTP_HEADER = 10
+    NONE = 255
+
+def detect(text: str) -> Case:
+    """The case of a word, `Case.NONE` when it has none."""
+
+def convert(text: str, to: Union[Case, int, str]) -> str:
+    """Converts a word like `wcase -w`, raising `ValueError` when it has no
+    case. `to` is a `Case` or its name, like `"snake"` or `"all-caps"`."""
+
+def convert_many(texts: Iterable[str], to: Union[Case, int, str]) -> List[str]:
+    """Converts every word of an iterable, like column names, to a list."""
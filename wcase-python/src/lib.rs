@@ -0,0 +1,92 @@
+//! Python extension of wcase, the `wcase._wcase` module behind the `wcase`
+//! package of `python/`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use std::str::FromStr;
+use wcase::{convert_lenient, get_case, Case};
+
+/// Cases by their number in Python, the values of the `Case` enum.
+const CASES: [(Case, u32); 12] = [
+    (Case::Flat, 0),
+    (Case::Upper, 1),
+    (Case::Camel, 2),
+    (Case::Pascal, 3),
+    (Case::Snake, 4),
+    (Case::AllCaps, 5),
+    (Case::Kebab, 6),
+    (Case::Train, 7),
+    (Case::Spaced, 8),
+    (Case::Title, 9),
+    (Case::HttpHeader, 10),
+    (Case::None, 255),
+];
+
+fn number(case: Case) -> u32 {
+    CASES.iter().find(|(c, _)| *c == case).unwrap().1
+}
+
+/// The case to convert to, from a `Case`, its number or its name like
+/// `"snake"` or `"all-caps"`.
+fn target(to: &Bound<'_, PyAny>) -> PyResult<Case> {
+    if to.is_instance_of::<PyString>() {
+        let name: String = to.extract()?;
+        return Case::from_str(&name).map_err(PyValueError::new_err);
+    }
+
+    let to: u32 = to.extract()?;
+
+    match CASES.iter().find(|(_, n)| *n == to) {
+        Some((Case::None, _)) => Err(PyValueError::new_err("Can't convert to Case.NONE")),
+        Some((case, _)) => Ok(*case),
+        None => Err(PyValueError::new_err(format!("Unknown case {}", to))),
+    }
+}
+
+fn convert_text(text: &str, to: Case) -> PyResult<String> {
+    match get_case(text) {
+        Case::None => Err(PyValueError::new_err(format!(
+            "The text has no case to convert from: {:?}",
+            text
+        ))),
+        case => Ok(wcase::convert(text, case, to)),
+    }
+}
+
+/// The number of the case of a word, the one of `Case.NONE` when it has none.
+#[pyfunction]
+fn detect(text: &str) -> u32 {
+    number(get_case(text))
+}
+
+/// Converts a word like `wcase -w`, raising `ValueError` when it has no case.
+#[pyfunction]
+fn convert(text: &str, to: &Bound<'_, PyAny>) -> PyResult<String> {
+    convert_text(text, target(to)?)
+}
+
+/// Converts every word of an iterable, like column names, to a list.
+#[pyfunction]
+fn convert_many(texts: &Bound<'_, PyAny>, to: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    let to = target(to)?;
+
+    texts
+        .try_iter()?
+        .map(|text| convert_text(&text?.extract::<String>()?, to))
+        .collect()
+}
+
+#[pymodule]
+fn _wcase(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let cases: Vec<(String, u32)> = CASES
+        .iter()
+        .map(|(case, number)| (convert_lenient(&case.to_string(), Case::AllCaps), *number))
+        .collect();
+
+    m.add("CASES", cases)?;
+    m.add_function(wrap_pyfunction!(detect, m)?)?;
+    m.add_function(wrap_pyfunction!(convert, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_many, m)?)?;
+    Ok(())
+}
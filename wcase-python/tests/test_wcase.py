@@ -0,0 +1,172 @@
+"""The cases of the Rust `mod tests` of wcase, through the Python package.
+
+Run with `PYTHONPATH=python python3 -m unittest discover tests` after
+`build.sh`.
+"""
+
+import ast
+import unittest
+from pathlib import Path
+
+import wcase
+from wcase import Case
+
+FLAT = "helloworld"
+UPPER = "HELLOWORLD"
+CAMEL = "helloWorld"
+BROKEN_PASCAL = "Helloworld"
+PASCAL = "HelloWorld"
+SNAKE = "hello_world"
+ALL_CAPS = "HELLO_WORLD"
+KEBAB = "hello-world"
+TRAIN = "HELLO-WORLD"
+SPACED = "hello world"
+TITLE = "Hello World"
+HTTP_HEADER = "Hello-World"
+
+WORDS = [
+    FLAT,
+    UPPER,
+    CAMEL,
+    PASCAL,
+    SNAKE,
+    ALL_CAPS,
+    KEBAB,
+    TRAIN,
+    SPACED,
+    TITLE,
+    HTTP_HEADER,
+]
+
+
+class ConvertTest(unittest.TestCase):
+    def assert_converts(self, to, from_flat, converted):
+        """Converts every word, the flat and upper ones to `from_flat`, which
+        have a single word."""
+        for word in WORDS:
+            expected = from_flat if word in (FLAT, UPPER) else converted
+
+            with self.subTest(word=word):
+                self.assertEqual(wcase.convert(word, to), expected)
+
+    def test_flat_case(self):
+        self.assert_converts(Case.FLAT, FLAT, FLAT)
+
+    def test_upper_case(self):
+        self.assert_converts(Case.UPPER, UPPER, UPPER)
+
+    def test_camel_case(self):
+        self.assert_converts(Case.CAMEL, FLAT, CAMEL)
+
+    def test_pascal_case(self):
+        self.assert_converts(Case.PASCAL, BROKEN_PASCAL, PASCAL)
+
+    def test_snake_case(self):
+        self.assert_converts(Case.SNAKE, FLAT, SNAKE)
+
+    def test_all_caps_case(self):
+        self.assert_converts(Case.ALL_CAPS, UPPER, ALL_CAPS)
+
+    def test_kebab_case(self):
+        self.assert_converts(Case.KEBAB, FLAT, KEBAB)
+
+    def test_train_case(self):
+        self.assert_converts(Case.TRAIN, UPPER, TRAIN)
+
+    def test_spaced_case(self):
+        self.assert_converts(Case.SPACED, FLAT, SPACED)
+
+    def test_title_case(self):
+        self.assert_converts(Case.TITLE, BROKEN_PASCAL, TITLE)
+
+    def test_http_header_case(self):
+        self.assert_converts(Case.HTTP_HEADER, BROKEN_PASCAL, HTTP_HEADER)
+
+    def test_case_names(self):
+        self.assertEqual(wcase.convert(CAMEL, "snake"), SNAKE)
+        self.assertEqual(wcase.convert(CAMEL, "all-caps"), ALL_CAPS)
+        self.assertEqual(wcase.convert(CAMEL, "HTTP_HEADER"), HTTP_HEADER)
+        self.assertEqual(wcase.convert(CAMEL, 6), KEBAB)
+
+        for to in ["dromedary", "none", Case.NONE, 11]:
+            with self.subTest(to=to), self.assertRaises(ValueError):
+                wcase.convert(CAMEL, to)
+
+    def test_invalid_words(self):
+        with self.assertRaisesRegex(ValueError, "no case to convert from"):
+            wcase.convert("hello-World", Case.SNAKE)
+
+        with self.assertRaises(TypeError):
+            wcase.convert(b"helloWorld", Case.SNAKE)
+
+    def test_empty_words(self):
+        self.assertEqual(wcase.convert("", Case.PASCAL), "")
+        self.assertEqual(wcase.convert("_", Case.CAMEL), "")
+        self.assertEqual(wcase.convert("_", Case.KEBAB), "-")
+
+    def test_null_characters(self):
+        self.assertEqual(wcase.convert("hello\0World", Case.SNAKE), "hello\0_world")
+
+    def test_non_ascii_words(self):
+        self.assertEqual(wcase.convert("caféCrème", "kebab"), "café-crème")
+
+    def test_convert_many(self):
+        columns = ["userId", "FirstName", "last_name", "E_MAIL"]
+
+        self.assertEqual(
+            wcase.convert_many(columns, "snake"),
+            ["user_id", "first_name", "last_name", "e_mail"],
+        )
+        self.assertEqual(wcase.convert_many(iter(columns[:1]), Case.TITLE), ["User Id"])
+        self.assertEqual(wcase.convert_many([], Case.SNAKE), [])
+
+        with self.assertRaises(ValueError):
+            wcase.convert_many(["userId", "hello-World"], Case.SNAKE)
+
+
+class DetectTest(unittest.TestCase):
+    def test_get_case(self):
+        cases = [
+            (FLAT, Case.FLAT),
+            (UPPER, Case.UPPER),
+            (CAMEL, Case.CAMEL),
+            (PASCAL, Case.PASCAL),
+            (SNAKE, Case.SNAKE),
+            (ALL_CAPS, Case.ALL_CAPS),
+            (KEBAB, Case.KEBAB),
+            (TRAIN, Case.TRAIN),
+            (SPACED, Case.SPACED),
+            (TITLE, Case.TITLE),
+            (HTTP_HEADER, Case.HTTP_HEADER),
+            ("hello-new_world", Case.NONE),
+            ("hello-World", Case.NONE),
+            ("hello new-world", Case.NONE),
+            ("hello_new-world of programming", Case.NONE),
+            ("Broken-HttP-Header", Case.NONE),
+            ("Broken space case", Case.NONE),
+        ]
+
+        for word, case in cases:
+            with self.subTest(word=word):
+                self.assertIs(wcase.detect(word), case)
+
+
+class CaseTest(unittest.TestCase):
+    def test_stub_has_the_cases_of_the_extension(self):
+        stub = Path(wcase.__file__).with_name("__init__.pyi").read_text()
+        case = next(
+            node
+            for node in ast.parse(stub).body
+            if isinstance(node, ast.ClassDef) and node.name == "Case"
+        )
+        members = {
+            node.targets[0].id: node.value.value
+            for node in case.body
+            if isinstance(node, ast.Assign)
+        }
+
+        self.assertEqual(members, {case.name: case.value for case in Case})
+
+
+if __name__ == "__main__":
+    unittest.main()